@@ -0,0 +1,52 @@
+use std::fmt::Arguments;
+use std::io::{stderr, Write};
+use std::sync::OnceLock;
+use std::thread::sleep;
+use std::time::Duration;
+
+/// Draws a frame of an animation if `aoc run --animate` turned animation on.
+///
+/// Takes a format string and its arguments, which are only formatted when
+/// animating. Each frame replaces the last one on the terminal:
+///
+/// ```
+/// # let (rock, tower) = (1, "|..##...|");
+/// aoc_common::animate!("rock {rock}\n{tower}");
+/// ```
+#[macro_export]
+macro_rules! animate {
+    ($($arg:tt)+) => {
+        if $crate::animate::enabled() {
+            $crate::animate::frame(format_args!($($arg)+));
+        }
+    };
+}
+
+// How long each frame stays up
+static FRAME_TIME: OnceLock<Duration> = OnceLock::new();
+
+/// Turns animation on for the rest of the process, at `fps` frames per
+/// second. Only the first call has any effect.
+pub fn enable(fps: u32) {
+    let _ = FRAME_TIME.set(Duration::from_secs(1) / fps.max(1));
+}
+
+pub fn enabled() -> bool {
+    FRAME_TIME.get().is_some()
+}
+
+/// Clears the terminal, draws `picture` on stderr, so that it doesn't mix
+/// with the answers, and waits until it's time for the next frame.
+pub fn frame(picture: Arguments) {
+    let Some(&frame_time) = FRAME_TIME.get() else {
+        return;
+    };
+
+    let mut stderr = stderr().lock();
+    // Move to the top left corner and clear the screen below it
+    let _ = writeln!(stderr, "\x1b[H\x1b[J{picture}");
+    let _ = stderr.flush();
+    drop(stderr);
+
+    sleep(frame_time);
+}
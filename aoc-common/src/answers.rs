@@ -0,0 +1,234 @@
+use std::fs::read_to_string;
+use std::path::Path;
+
+use crate::config::{self, Config};
+use crate::input::{day_directory, InputSource};
+use crate::solution::{format_answers, solve, Solution, Solved};
+
+/// Solves every input listed in `answers.txt` in a day's `directory`, and
+/// panics listing each one whose answers changed.
+///
+/// The manifest has a `[file name]` header for each input, followed by the
+/// expected output of `aoc run` for it. Parts left out aren't solved, for
+/// inputs that only one part handles. Lines starting with `#` are comments.
+///
+/// The file name can be followed by parameters to solve it with, written as
+/// they would be for `aoc run --set`, such as `[test_input.txt row=10]`.
+pub fn check<S: Solution>(directory: impl AsRef<Path>) {
+    let directory = directory.as_ref();
+    let manifest = directory.join("answers.txt");
+    let manifest = read_to_string(&manifest)
+        .unwrap_or_else(|err| panic!("couldn't read {}: {err}", manifest.display()));
+
+    let mut changed = vec![];
+    for (header, expected) in parse_manifest(&manifest) {
+        let mut words = header.split_whitespace();
+        let file = words.next().unwrap_or_default();
+
+        let mut config = Config::new();
+        for setting in words {
+            config
+                .set(setting)
+                .unwrap_or_else(|err| panic!("{file}: {err}"));
+        }
+        config
+            .validate(|day| if day == S::DAY { S::PARAMETERS } else { &[] })
+            .unwrap_or_else(|err| panic!("{file}: {err}"));
+
+        let path = directory.join(file);
+        let input = read_to_string(&path)
+            .unwrap_or_else(|err| panic!("couldn't read {}: {err}", path.display()));
+
+        let found = config::scoped(config, || match solve::<S>(&input, only_part(&expected)) {
+            Ok(answers) => format_answers(&answers),
+            Err(err) => err.to_string(),
+        });
+
+        if normalize(&found) != normalize(&expected) {
+            changed.push(format!("{file}: expected\n{expected}found\n{found}"));
+        }
+    }
+
+    assert!(
+        changed.is_empty(),
+        "day {:02} answers changed\n\n{}",
+        S::DAY,
+        changed.join("\n")
+    );
+}
+
+/// The output `answers.txt` in a day's `directory` expects for `file`, when
+/// it's solved with the [`parameters`] listed for it.
+pub fn expected(directory: impl AsRef<Path>, file: &str) -> Option<String> {
+    let manifest = read_to_string(directory.as_ref().join("answers.txt")).ok()?;
+    parse_manifest(&manifest)
+        .into_iter()
+        .find(|(header, _)| header.split_whitespace().next() == Some(file))
+        .map(|(_, expected)| expected)
+}
+
+/// The parameters `answers.txt` in a day's `directory` lists after `file`,
+/// as `name=value` settings, such as day 15's example asking about row 10
+/// rather than the real input's row.
+pub fn parameters(directory: impl AsRef<Path>, file: &str) -> Vec<String> {
+    let Ok(manifest) = read_to_string(directory.as_ref().join("answers.txt")) else {
+        return vec![];
+    };
+    let mut headers = parse_manifest(&manifest)
+        .into_iter()
+        .map(|(header, _)| header.split_whitespace());
+
+    headers
+        .find_map(|mut words| {
+            (words.next() == Some(file)).then(|| words.map(String::from).collect())
+        })
+        .unwrap_or_default()
+}
+
+/// Adds the [`parameters`] for the day's input to `config`, when it's one of
+/// the day's own named inputs, so a fixture is solved as the manifest solves
+/// it wherever it's run from.
+pub fn configure(config: &mut Config, day: u8, source: &InputSource) -> Result<(), String> {
+    if !matches!(source, InputSource::Real | InputSource::Test(_)) {
+        return Ok(());
+    }
+
+    let directory = day_directory(day);
+    let path = source.path(&directory);
+    let file = path.file_name().unwrap_or_default().to_string_lossy();
+    for setting in parameters(&directory, &file) {
+        config
+            .set_for(day, &setting)
+            .map_err(|err| format!("{file}: {err}"))?;
+    }
+
+    Ok(())
+}
+
+/// Whether `solved` is the answer `expected` gives for its part, or `None` if
+/// it gives none.
+pub fn matches(expected: &str, solved: &Solved) -> Option<bool> {
+    let mut parts: Vec<(u8, String)> = vec![];
+    // Art runs on from its `partN =` line until the next part's
+    for line in expected.lines() {
+        if let Some(part) = (1..=2).find(|part| line.starts_with(&format!("part{part} ="))) {
+            parts.push((part, String::new()));
+        }
+        if let Some((_, text)) = parts.last_mut() {
+            *text += line;
+            text.push('\n');
+        }
+    }
+
+    let (_, expected) = parts.iter().find(|(part, _)| *part == solved.part)?;
+    let found = format_answers(std::slice::from_ref(solved));
+    Some(normalize(&found) == normalize(expected))
+}
+
+/// Splits the manifest into each input's file name and expected output.
+fn parse_manifest(manifest: &str) -> Vec<(&str, String)> {
+    let mut sections: Vec<(&str, String)> = vec![];
+
+    for line in manifest.lines().filter(|line| !line.starts_with('#')) {
+        if let Some(file) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            sections.push((file, String::new()));
+        } else if let Some((_, expected)) = sections.last_mut() {
+            *expected += line;
+            expected.push('\n');
+        } else {
+            assert!(line.is_empty(), "answer outside of a section: {line:?}");
+        }
+    }
+
+    sections
+}
+
+fn only_part(expected: &str) -> Option<u8> {
+    let has = |part: u8| {
+        expected
+            .lines()
+            .any(|line| line.starts_with(&format!("part{part} =")))
+    };
+
+    match (has(1), has(2)) {
+        (true, false) => Some(1),
+        (false, true) => Some(2),
+        _ => None,
+    }
+}
+
+// Editors strip trailing whitespace, which art is full of
+fn normalize(output: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Answer;
+    use std::time::Duration;
+
+    #[test]
+    fn parses_sections_and_skips_comments() {
+        let manifest =
+            "# comment\n[input.txt]\npart1 = 1\npart2 = 2\n\n[test_input.txt]\npart1 = 3\n";
+        let sections = parse_manifest(manifest);
+
+        assert_eq!(
+            sections,
+            [
+                ("input.txt", "part1 = 1\npart2 = 2\n\n".to_string()),
+                ("test_input.txt", "part1 = 3\n".to_string()),
+            ]
+        );
+        assert_eq!(only_part(&sections[0].1), None);
+        assert_eq!(only_part(&sections[1].1), Some(1));
+    }
+
+    #[test]
+    fn reads_a_fixtures_parameters() {
+        let directory = day_directory(15);
+        assert_eq!(
+            parameters(&directory, "test_input.txt"),
+            ["row=10", "limit=20"]
+        );
+        assert!(parameters(&directory, "input.txt").is_empty());
+
+        let mut config = Config::new();
+        configure(&mut config, 15, &InputSource::Test(None)).unwrap();
+        let mut expected = Config::new();
+        expected.set("day-15.row=10").unwrap();
+        expected.set("day-15.limit=20").unwrap();
+        assert_eq!(config, expected);
+    }
+
+    #[test]
+    fn matches_each_part_on_its_own() {
+        let expected = "part1 = 7\npart2 =\n#.\n.#\n";
+        let solved = |part, answer| Solved {
+            part,
+            answer,
+            elapsed: Duration::ZERO,
+        };
+        let art = |rows: &[&str]| Answer::Art(rows.iter().map(|row| row.to_string()).collect());
+
+        assert_eq!(matches(expected, &solved(1, Answer::Number(7))), Some(true));
+        assert_eq!(
+            matches(expected, &solved(2, art(&["#.", ".#"]))),
+            Some(true)
+        );
+        assert_eq!(
+            matches(expected, &solved(2, art(&["##", ".#"]))),
+            Some(false)
+        );
+        assert_eq!(matches("part1 = 7\n", &solved(2, Answer::Number(7))), None);
+    }
+}
@@ -0,0 +1,37 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+/// How long each phase took on every iteration of a benchmark.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Samples {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    /// Empty for days without a second puzzle
+    pub part2: Vec<Duration>,
+}
+
+/// Parses `input` and solves both parts `iterations` times, timing each phase
+/// separately.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<Samples, ParseError> {
+    let mut samples = Samples::default();
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input)).map_err(|err| err.locate(S::DAY, input))?;
+        samples.parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        samples.part1.push(start.elapsed());
+
+        let start = Instant::now();
+        if black_box(S::part2(&parsed)).is_some() {
+            samples.part2.push(start.elapsed());
+        }
+    }
+
+    Ok(samples)
+}
@@ -0,0 +1,400 @@
+use std::fmt;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of numbers below `64 * WORDS`, as bits, so it's `Copy` and cheap to
+/// hash and compare for use in search states.
+///
+/// Inserting a number that doesn't fit panics rather than losing it.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BitSet<const WORDS: usize = 1> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> BitSet<WORDS> {
+    /// How many numbers fit: every number in the set is below this.
+    pub const CAPACITY: usize = BITS * WORDS;
+
+    pub const fn new() -> Self {
+        Self { words: [0; WORDS] }
+    }
+
+    /// Whether `n` was newly added.
+    pub fn insert(&mut self, n: usize) -> bool {
+        assert!(
+            n < Self::CAPACITY,
+            "{n} doesn't fit in a set of {} bits",
+            Self::CAPACITY
+        );
+        let (word, bit) = (n / BITS, 1 << (n % BITS));
+        let added = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        added
+    }
+
+    /// Whether `n` was in the set.
+    pub fn remove(&mut self, n: usize) -> bool {
+        let present = self.contains(n);
+        if present {
+            self.words[n / BITS] &= !(1 << (n % BITS));
+        }
+        present
+    }
+
+    pub fn contains(&self, n: usize) -> bool {
+        n < Self::CAPACITY && self.words[n / BITS] & (1 << (n % BITS)) != 0
+    }
+
+    /// A copy with `n` added too.
+    pub fn with(mut self, n: usize) -> Self {
+        self.insert(n);
+        self
+    }
+
+    /// A copy without `n`.
+    pub fn without(mut self, n: usize) -> Self {
+        self.remove(n);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        count(&self.words)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            words: std::array::from_fn(|i| self.words[i] | other.words[i]),
+        }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            words: std::array::from_fn(|i| self.words[i] & other.words[i]),
+        }
+    }
+
+    /// The numbers in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            words: std::array::from_fn(|i| self.words[i] & !other.words[i]),
+        }
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        disjoint(&self.words, &other.words)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// The numbers in the set, smallest first.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(&self.words)
+    }
+}
+
+impl<const WORDS: usize> Default for BitSet<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WORDS: usize> FromIterator<usize> for BitSet<WORDS> {
+    fn from_iter<I: IntoIterator<Item = usize>>(numbers: I) -> Self {
+        let mut set = Self::new();
+        set.extend(numbers);
+        set
+    }
+}
+
+impl<const WORDS: usize> Extend<usize> for BitSet<WORDS> {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, numbers: I) {
+        for n in numbers {
+            self.insert(n);
+        }
+    }
+}
+
+impl<'a, const WORDS: usize> IntoIterator for &'a BitSet<WORDS> {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl<const WORDS: usize> fmt::Debug for BitSet<WORDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// A set of numbers as bits, like [`BitSet`], that grows to fit whatever is
+/// inserted, for sets too big to size up front.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct GrowableBitSet {
+    // Never with a zero word last, so equal sets have equal words
+    words: Vec<u64>,
+}
+
+impl GrowableBitSet {
+    pub const fn new() -> Self {
+        Self { words: vec![] }
+    }
+
+    /// An empty set with room for numbers below `capacity` before it grows.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(capacity.div_ceil(BITS)),
+        }
+    }
+
+    /// Whether `n` was newly added.
+    pub fn insert(&mut self, n: usize) -> bool {
+        let (word, bit) = (n / BITS, 1 << (n % BITS));
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let added = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        added
+    }
+
+    /// Whether `n` was in the set.
+    pub fn remove(&mut self, n: usize) -> bool {
+        let present = self.contains(n);
+        if present {
+            self.words[n / BITS] &= !(1 << (n % BITS));
+            self.trim();
+        }
+        present
+    }
+
+    pub fn contains(&self, n: usize) -> bool {
+        self.words
+            .get(n / BITS)
+            .is_some_and(|word| word & (1 << (n % BITS)) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        count(&self.words)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let (longer, shorter) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut words = longer.words.clone();
+        for (word, other) in words.iter_mut().zip(&shorter.words) {
+            *word |= other;
+        }
+        Self { words }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let words = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| a & b)
+            .collect();
+        Self::trimmed(words)
+    }
+
+    /// The numbers in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let words = self
+            .words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| word & !other.words.get(i).copied().unwrap_or(0))
+            .collect();
+        Self::trimmed(words)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        disjoint(&self.words, &other.words)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// The numbers in the set, smallest first.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(&self.words)
+    }
+
+    fn trimmed(words: Vec<u64>) -> Self {
+        let mut set = Self { words };
+        set.trim();
+        set
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+}
+
+impl FromIterator<usize> for GrowableBitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(numbers: I) -> Self {
+        let mut set = Self::new();
+        set.extend(numbers);
+        set
+    }
+}
+
+impl Extend<usize> for GrowableBitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, numbers: I) {
+        for n in numbers {
+            self.insert(n);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a GrowableBitSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for GrowableBitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// The numbers in a [`BitSet`] or [`GrowableBitSet`], smallest first.
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    words: &'a [u64],
+    // What's left of the word at `offset`, with the bits already seen cleared
+    word: u64,
+    offset: usize,
+}
+
+impl<'a> Iter<'a> {
+    fn new(words: &'a [u64]) -> Self {
+        match words.split_first() {
+            Some((&word, words)) => Self {
+                words,
+                word,
+                offset: 0,
+            },
+            None => Self {
+                words,
+                word: 0,
+                offset: 0,
+            },
+        }
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            let (&word, words) = self.words.split_first()?;
+            (self.word, self.words) = (word, words);
+            self.offset += BITS;
+        }
+
+        let bit = self.word.trailing_zeros() as usize;
+        // Clears the lowest set bit
+        self.word &= self.word - 1;
+        Some(self.offset + bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.word.count_ones() as usize + count(self.words);
+        (left, Some(left))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+fn count(words: &[u64]) -> usize {
+    words.iter().map(|word| word.count_ones() as usize).sum()
+}
+
+fn disjoint(a: &[u64], b: &[u64]) -> bool {
+    a.iter().zip(b).all(|(a, b)| a & b == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_sets() {
+        let mut set: BitSet<2> = [3, 70, 64, 0].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 3, 64, 70]);
+        assert_eq!(set.len(), 4);
+        assert!(set.contains(70) && !set.contains(71) && !set.contains(500));
+        assert!(!set.insert(3));
+        assert!(set.remove(3));
+        assert!(!set.remove(3));
+        assert_eq!(format!("{set:?}"), "{0, 64, 70}");
+
+        let other = BitSet::<2>::new().with(64).with(127);
+        assert_eq!(
+            set.union(&other).iter().collect::<Vec<_>>(),
+            [0, 64, 70, 127]
+        );
+        assert_eq!(set.intersection(&other).iter().collect::<Vec<_>>(), [64]);
+        assert_eq!(set.difference(&other).iter().collect::<Vec<_>>(), [0, 70]);
+        assert!(!set.is_disjoint(&other));
+        assert!(set.is_disjoint(&other.without(64)));
+        assert!(BitSet::<2>::new().with(70).is_subset(&set));
+        assert!(BitSet::<1>::new().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "64 doesn't fit in a set of 64 bits")]
+    fn fixed_sets_refuse_what_doesnt_fit() {
+        BitSet::<1>::new().insert(64);
+    }
+
+    #[test]
+    fn growable_sets() {
+        let mut set: GrowableBitSet = [1000, 5, 64].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [5, 64, 1000]);
+        assert_eq!(set.iter().len(), 3);
+        assert!(set.contains(1000) && !set.contains(1_000_000));
+
+        // Equal however big they grew on the way
+        assert!(set.remove(1000));
+        assert_eq!(set, [64, 5].into_iter().collect());
+
+        let other: GrowableBitSet = [5, 200].into_iter().collect();
+        assert_eq!(set.union(&other).iter().collect::<Vec<_>>(), [5, 64, 200]);
+        assert_eq!(set.intersection(&other).iter().collect::<Vec<_>>(), [5]);
+        assert_eq!(set.difference(&other).iter().collect::<Vec<_>>(), [64]);
+        assert_eq!(other.difference(&set), [200].into_iter().collect());
+        assert!(!set.is_disjoint(&other));
+        assert!(set.intersection(&other).is_subset(&set));
+        assert!(set.intersection(&GrowableBitSet::new()).is_empty());
+    }
+}
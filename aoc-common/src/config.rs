@@ -0,0 +1,496 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::RwLock;
+
+/// Numbers and names the puzzles fix, such as how many rounds to simulate,
+/// which a day reads with [`parameter`] so they can be changed without
+/// touching its code.
+///
+/// They're set by day in `aoc.toml`, one `[day-NN]` table each:
+///
+/// ```toml
+/// [day-15]
+/// row = 10
+/// limit = 20
+/// ```
+///
+/// Only tables of integers, strings and arrays of them are understood. Each
+/// day declares the parameters it reads, which [`Config::validate`] checks
+/// settings against, so a typo or a bad value is found before any day runs.
+/// Settings from `aoc run --set` go on top, as `day-15.row=10`, or as
+/// `row=10` for every day being run that doesn't set `row` for itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    days: BTreeMap<u8, BTreeMap<String, String>>,
+    every_day: BTreeMap<String, String>,
+}
+
+impl Config {
+    pub const fn new() -> Self {
+        Self {
+            days: BTreeMap::new(),
+            every_day: BTreeMap::new(),
+        }
+    }
+
+    /// Reads `path`, or gives an empty config if it's the default path and
+    /// there's nothing there.
+    pub fn load(path: Option<&Path>) -> Result<Self, String> {
+        let default = default_path();
+        let path = path.unwrap_or(&default);
+        let text = match read_to_string(path) {
+            Ok(text) => text,
+            Err(_) if path == default && !path.exists() => return Ok(Self::new()),
+            Err(err) => return Err(format!("couldn't read {}: {err}", path.display())),
+        };
+
+        text.parse()
+            .map_err(|err| format!("couldn't parse {}: {err}", path.display()))
+    }
+
+    /// Adds a `name=value` setting for every day, or a `day-NN.name=value`
+    /// one for a single day, over whatever was there.
+    pub fn set(&mut self, setting: &str) -> Result<(), String> {
+        let (key, value) = split_setting(setting)?;
+
+        match key {
+            (Some(day), name) => self.days.entry(day).or_default().insert(name, value),
+            (None, name) => self.every_day.insert(name, value),
+        };
+        Ok(())
+    }
+
+    /// Adds a `name=value` setting for `day` alone, over whatever was there.
+    pub fn set_for(&mut self, day: u8, setting: &str) -> Result<(), String> {
+        let (key, value) = split_setting(setting)?;
+        let name = match key {
+            (Some(other), _) if other != day => {
+                return Err(format!(
+                    "`{setting}` is for day {other:02}, not day {day:02}"
+                ))
+            }
+            (_, name) => name,
+        };
+        self.days.entry(day).or_default().insert(name, value);
+        Ok(())
+    }
+
+    /// Only the settings `day` reads, for a binary that solves nothing else.
+    pub fn for_day(mut self, day: u8) -> Self {
+        self.days.retain(|&number, _| number == day);
+        self
+    }
+
+    /// Checks every setting against the parameters each day declares,
+    /// returning an error listing the names no day reads and the values
+    /// that aren't what their parameter expects.
+    pub fn validate(&self, parameters: impl Fn(u8) -> &'static [Parameter]) -> Result<(), String> {
+        let mut problems = vec![];
+
+        for (&day, settings) in &self.days {
+            for (name, value) in settings {
+                match parameters(day)
+                    .iter()
+                    .find(|parameter| parameter.name == name)
+                {
+                    Some(parameter) => problems.extend(parameter.check(day, value)),
+                    None => problems.push(unknown(day, name, parameters(day))),
+                }
+            }
+        }
+
+        for (name, value) in &self.every_day {
+            let declared: Vec<(u8, &Parameter)> = (1..=25)
+                .filter_map(|day| {
+                    let parameter = parameters(day).iter().find(|p| p.name == name)?;
+                    Some((day, parameter))
+                })
+                .collect();
+            if declared.is_empty() {
+                problems.push(format!("no day has a parameter `{name}`"));
+            }
+            for (day, parameter) in declared {
+                problems.extend(parameter.check(day, value));
+            }
+        }
+
+        match problems.is_empty() {
+            true => Ok(()),
+            false => Err(problems.join("\n")),
+        }
+    }
+
+    // A day's own setting wins over one for every day
+    fn get(&self, day: u8, name: &str) -> Option<&str> {
+        self.days
+            .get(&day)
+            .and_then(|settings| settings.get(name))
+            .or_else(|| self.every_day.get(name))
+            .map(String::as_str)
+    }
+}
+
+impl FromStr for Config {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut config = Self::new();
+        let mut table = None;
+
+        for (number, line) in text.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            let error = |message: String| format!("line {}: {message}", number + 1);
+
+            if line.is_empty() {
+                continue;
+            } else if let Some(name) = line.strip_prefix('[') {
+                let name = name
+                    .strip_suffix(']')
+                    .ok_or_else(|| error(format!("expected `]` after `{line}`")))?;
+                table = Some(day_number(name.trim()).map_err(error)?);
+            } else {
+                let (key, value) = line
+                    .split_once('=')
+                    .ok_or_else(|| error(format!("expected `name = value`, got `{line}`")))?;
+                let (day, name) = match (table, split_key(key.trim()).map_err(error)?) {
+                    (_, (Some(day), name)) | (Some(day), (None, name)) => (day, name),
+                    (None, (None, name)) => {
+                        return Err(error(format!("`{name}` isn't under a day's table")))
+                    }
+                };
+
+                let value = normalize(value.trim()).map_err(error)?;
+                config.days.entry(day).or_default().insert(name, value);
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// A parameter a day reads with [`parameter`] or [`list`], and the type its
+/// value has to parse as.
+#[derive(Debug, Clone, Copy)]
+pub struct Parameter {
+    pub name: &'static str,
+    parses: fn(&str) -> bool,
+    type_name: fn() -> &'static str,
+    list: bool,
+}
+
+impl Parameter {
+    /// One read with [`parameter`].
+    pub const fn new<T: FromStr>(name: &'static str) -> Self {
+        Self {
+            name,
+            parses: parses::<T>,
+            type_name: std::any::type_name::<T>,
+            list: false,
+        }
+    }
+
+    /// One read with [`list`].
+    pub const fn list<T: FromStr>(name: &'static str) -> Self {
+        Self {
+            list: true,
+            ..Self::new::<T>(name)
+        }
+    }
+
+    // What's wrong with `value`, if anything, in the words `parameter` and
+    // `list` panic with
+    fn check(&self, day: u8, value: &str) -> Option<String> {
+        let name = self.name;
+        let type_name = unqualified((self.type_name)());
+        if !self.list {
+            return (!(self.parses)(value))
+                .then(|| format!("day {day:02}'s `{name}` should be {type_name}, got `{value}`"));
+        }
+
+        let item = value
+            .split(',')
+            .filter(|_| !value.is_empty())
+            .find(|item| !(self.parses)(item))?;
+        Some(format!(
+            "day {day:02}'s `{name}` should be an array of {type_name}, got `{item}`"
+        ))
+    }
+}
+
+fn parses<T: FromStr>(value: &str) -> bool {
+    value.parse::<T>().is_ok()
+}
+
+// `NonZero<u64>` rather than `core::num::nonzero::NonZero<u64>`
+fn unqualified(type_name: &str) -> String {
+    type_name
+        .split_inclusive(['<', '>', ',', ' '])
+        .map(|piece| piece.rsplit("::").next().unwrap_or(piece))
+        .collect()
+}
+
+fn unknown(day: u8, name: &str, parameters: &[Parameter]) -> String {
+    let names: Vec<&str> = parameters.iter().map(|parameter| parameter.name).collect();
+    match names[..] {
+        [] => format!("day {day:02} has no parameters, but `{name}` is set"),
+        _ => format!(
+            "day {day:02} has no parameter `{name}`, only {}",
+            names.join(", ")
+        ),
+    }
+}
+
+static CONFIG: RwLock<Config> = RwLock::new(Config::new());
+
+thread_local! {
+    static SCOPED: RefCell<Option<Config>> = const { RefCell::new(None) };
+}
+
+/// Makes `config` the one that [`parameter`] reads, for the rest of the
+/// process or until the next call.
+pub fn install(config: Config) {
+    *CONFIG.write().unwrap() = config;
+}
+
+/// Runs `f` with `config` as the one that [`parameter`] reads on this thread
+/// instead of the installed one, so tests solving with parameters of their
+/// own at the same time don't see each other's.
+pub fn scoped<T>(config: Config, f: impl FnOnce() -> T) -> T {
+    // Puts back whatever was there even if `f` panics
+    struct Restore(Option<Config>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(SCOPED.replace(Some(config)));
+    f()
+}
+
+fn setting(day: u8, name: &str) -> Option<String> {
+    SCOPED.with_borrow(|scoped| match scoped {
+        Some(config) => config.get(day, name).map(String::from),
+        None => CONFIG.read().unwrap().get(day, name).map(String::from),
+    })
+}
+
+/// The value of a day's parameter, or `default` if nothing sets it.
+///
+/// Panics if the value that's set isn't a `T`, which [`Config::validate`]
+/// finds first for a parameter the day declares as one.
+pub fn parameter<T: FromStr>(day: u8, name: &str, default: T) -> T {
+    match setting(day, name) {
+        None => default,
+        Some(value) => value.parse().unwrap_or_else(|_| {
+            panic!(
+                "day {day:02}'s `{name}` should be {}, got `{value}`",
+                unqualified(std::any::type_name::<T>())
+            )
+        }),
+    }
+}
+
+/// The value of a day's parameter that's an array, or `default` if nothing
+/// sets it.
+///
+/// Panics if any item that's set isn't a `T`.
+pub fn list<T: FromStr>(day: u8, name: &str, default: Vec<T>) -> Vec<T> {
+    match setting(day, name).as_deref() {
+        None => default,
+        Some("") => vec![],
+        Some(value) => value
+            .split(',')
+            .map(|item| {
+                item.parse().unwrap_or_else(|_| {
+                    panic!(
+                        "day {day:02}'s `{name}` should be an array of {}, got `{item}`",
+                        unqualified(std::any::type_name::<T>())
+                    )
+                })
+            })
+            .collect(),
+    }
+}
+
+/// `aoc.toml` at the root of the workspace.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("aoc.toml")
+}
+
+// A `#` starts a comment unless it's inside a string
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+fn day_number(table: &str) -> Result<u8, String> {
+    table
+        .strip_prefix("day-")
+        .and_then(|number| number.parse().ok())
+        .filter(|number| (1..=25).contains(number))
+        .ok_or_else(|| format!("expected a table named `day-01` to `day-25`, got `{table}`"))
+}
+
+fn split_setting(setting: &str) -> Result<((Option<u8>, String), String), String> {
+    let (key, value) = setting
+        .split_once('=')
+        .ok_or_else(|| format!("expected `name=value`, got `{setting}`"))?;
+
+    Ok((split_key(key.trim())?, normalize(value.trim())?))
+}
+
+// `day-NN.name` belongs to day NN, and a bare `name` to whichever day it's
+// under
+fn split_key(key: &str) -> Result<(Option<u8>, String), String> {
+    let (day, name) = match key.split_once('.') {
+        Some((table, name)) => (Some(day_number(table)?), name),
+        None => (None, key),
+    };
+
+    let bare = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+    if name.is_empty() || !name.chars().all(bare) {
+        return Err(format!("expected a parameter name, got `{key}`"));
+    }
+
+    Ok((day, name.to_string()))
+}
+
+// Values are kept as the text `FromStr` expects: integers without their
+// underscores, strings without their quotes, and arrays as their items
+// joined by commas
+fn normalize(value: &str) -> Result<String, String> {
+    if let Some(items) = value.strip_prefix('[') {
+        let items = items
+            .strip_suffix(']')
+            .ok_or_else(|| format!("expected `]` to end `{value}`"))?;
+        let items: Vec<String> = items
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(normalize)
+            .collect::<Result<_, _>>()?;
+        return Ok(items.join(","));
+    }
+
+    if let Some(string) = value.strip_prefix('"') {
+        return string
+            .strip_suffix('"')
+            .map(String::from)
+            .ok_or_else(|| format!("expected `\"` to end `{value}`"));
+    }
+
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit() || c == '_') {
+        return Ok(value.replace(['_', '+'], ""));
+    }
+
+    // Anything else is kept as it is, such as a word from the command line
+    // whose quotes the shell took off
+    Ok(value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_tables_and_overrides() {
+        let mut config: Config = "# comment\n[day-15]\nrow = 2_000_000 # the real input's\n\n[day-16]\nstart = \"AA\"\n\n[day-20]\noffsets = [1000, 2000, 3000]\n"
+            .parse()
+            .unwrap();
+        assert_eq!(config.get(15, "row"), Some("2000000"));
+        assert_eq!(config.get(16, "start"), Some("AA"));
+        assert_eq!(config.get(20, "offsets"), Some("1000,2000,3000"));
+        assert_eq!(config.get(15, "start"), None);
+
+        config.set("day-15.row=10").unwrap();
+        config.set("start=BB").unwrap();
+        assert_eq!(config.get(15, "row"), Some("10"));
+        assert_eq!(config.get(15, "start"), Some("BB"));
+        assert_eq!(config.get(16, "start"), Some("AA"));
+
+        assert_eq!(
+            "row = 10".parse::<Config>(),
+            Err("line 1: `row` isn't under a day's table".to_string())
+        );
+        assert!(config.set("day-26.row=10").is_err());
+
+        config.set_for(15, "row=12").unwrap();
+        assert_eq!(config.get(15, "row"), Some("12"));
+        assert!(config.set_for(15, "day-16.start=CC").is_err());
+    }
+
+    #[test]
+    fn validates_against_declared_parameters() {
+        const DAY_11: &[Parameter] = &[Parameter::new::<std::num::NonZeroU64>("relief")];
+        const DAY_15: &[Parameter] = &[Parameter::new::<isize>("row")];
+        const DAY_20: &[Parameter] = &[Parameter::list::<usize>("offsets")];
+        let parameters = |day| match day {
+            11 => DAY_11,
+            15 => DAY_15,
+            20 => DAY_20,
+            _ => &[],
+        };
+
+        let mut config: Config = "[day-15]\nrow = 10\n[day-20]\noffsets = []\n"
+            .parse()
+            .unwrap();
+        assert_eq!(config.validate(parameters), Ok(()));
+
+        config.set("day-11.relief=0").unwrap();
+        config.set("day-15.row=abc").unwrap();
+        config.set("day-20.offsets=[1, -2]").unwrap();
+        config.set("day-07.nonexistent=3").unwrap();
+        config.set("day-15.rows=3").unwrap();
+        config.set("limit=3").unwrap();
+        assert_eq!(
+            config.validate(parameters),
+            Err([
+                "day 07 has no parameters, but `nonexistent` is set",
+                "day 11's `relief` should be NonZero<u64>, got `0`",
+                "day 15's `row` should be isize, got `abc`",
+                "day 15 has no parameter `rows`, only row",
+                "day 20's `offsets` should be an array of usize, got `-2`",
+                "no day has a parameter `limit`",
+            ]
+            .join("\n"))
+        );
+    }
+
+    #[test]
+    fn scoped_configs_stay_on_their_thread() {
+        let config = |setting| {
+            let mut config = Config::new();
+            config.set(setting).unwrap();
+            config
+        };
+
+        scoped(config("day-15.row=10"), || {
+            assert_eq!(parameter(15, "row", 0), 10);
+            std::thread::scope(|threads| {
+                threads.spawn(|| {
+                    scoped(config("day-15.row=20"), || {
+                        assert_eq!(parameter(15, "row", 0), 20)
+                    })
+                });
+            });
+            assert_eq!(parameter(15, "row", 0), 10);
+        });
+        assert_eq!(parameter(15, "row", 0), 0);
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+/// Finds where a simulation starts repeating itself, given a key for each
+/// state it goes through and a value tracked alongside, such as a height or a
+/// score.
+///
+/// The key must hold everything that decides the states after it, so that
+/// two states with the same key are followed by the same steps.
+#[derive(Debug, Clone)]
+pub struct CycleDetector<K, V> {
+    seen: HashMap<K, usize>,
+    values: Vec<V>,
+}
+
+/// A stretch of `period` steps that repeats forever once the simulation
+/// reaches step `start`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<V> {
+    pub start: usize,
+    pub period: usize,
+    // The value at every step up to the first repeat, included
+    values: Vec<V>,
+}
+
+impl<K: Eq + Hash, V> CycleDetector<K, V> {
+    pub fn new() -> Self {
+        Self {
+            seen: HashMap::new(),
+            values: vec![],
+        }
+    }
+
+    /// Records the state after the steps pushed so far, the first being
+    /// step 0, and returns the cycle once a key comes round again.
+    pub fn push(&mut self, key: K, value: V) -> Option<Cycle<V>> {
+        let step = self.values.len();
+        self.values.push(value);
+
+        if let Some(&start) = self.seen.get(&key) {
+            return Some(Cycle {
+                start,
+                period: step - start,
+                values: std::mem::take(&mut self.values),
+            });
+        }
+
+        self.seen.insert(key, step);
+        None
+    }
+}
+
+impl<K: Eq + Hash, V> Default for CycleDetector<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Cycle<V>
+where
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<Output = V> + TryFrom<usize>,
+{
+    /// How much the value changes each time round the cycle.
+    pub fn delta(&self) -> V {
+        self.values[self.start + self.period] - self.values[self.start]
+    }
+
+    /// The value at any `step`, however far past the ones simulated.
+    pub fn value_at(&self, step: usize) -> V {
+        if step < self.start {
+            return self.values[step];
+        }
+
+        let cycles = (step - self.start) / self.period;
+        let cycles = V::try_from(cycles)
+            .unwrap_or_else(|_| panic!("{cycles} cycles don't fit in the value type"));
+        let offset = (step - self.start) % self.period;
+
+        self.values[self.start + offset] + self.delta() * cycles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extrapolates_a_cycle_after_a_lead_in() {
+        // 0, 1, then 2, 3, 4 going up by 10 each time round
+        let mut detector = CycleDetector::new();
+        let states = [0, 1, 2, 3, 4, 2];
+        let values = [0, 5, 7, 12, 15, 17];
+
+        let cycle = states
+            .into_iter()
+            .zip(values)
+            .find_map(|(state, value)| detector.push(state, value))
+            .unwrap();
+
+        assert_eq!((cycle.start, cycle.period), (2, 3));
+        assert_eq!(cycle.delta(), 10);
+        assert_eq!(cycle.value_at(1), 5);
+        assert_eq!(cycle.value_at(5), 17);
+        assert_eq!(cycle.value_at(9), 32);
+        assert_eq!(cycle.value_at(3_000_000_002), 10_000_000_007_u64);
+    }
+}
@@ -0,0 +1,85 @@
+use std::ops::RangeInclusive;
+
+use crate::solution::Solution;
+
+/// A seeded pseudo-random number generator (SplitMix64), so that a seed
+/// makes the same input on every machine.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "empty range {low}..={high}");
+
+        let span = high.abs_diff(low) as u128 + 1;
+        let offset = ((u128::from(self.next_u64()) * span) >> 64) as i64;
+        low.wrapping_add(offset)
+    }
+
+    /// An index into something `len` long, which must not be empty.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "no index into nothing");
+        self.range(0..=len as i64 - 1) as usize
+    }
+
+    /// `true` with the given `probability`.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64) < probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Generates an input for day `S` from `seed`; see [`Solution::generate`].
+pub fn generate<S: Solution>(seed: u64, size: usize) -> String {
+    S::generate(&mut Rng::new(seed), size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_repeat_and_ranges_hold() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..100).map(|_| rng.range(-3..=3)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(1), numbers(1));
+        assert_ne!(numbers(1), numbers(2));
+        assert!(numbers(1).iter().all(|n| (-3..=3).contains(n)));
+        assert!((-3..=3).all(|n| numbers(1).contains(&n)));
+
+        let mut rng = Rng::new(0);
+        assert_eq!(rng.range(i64::MIN..=i64::MIN), i64::MIN);
+        let mut items = [1, 2, 3, 4];
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,331 @@
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::Neg;
+
+use Direction::*;
+
+/// An integer type usable as a coordinate in [`Point2`] and [`Point3`].
+pub trait Coordinate: Copy + Ord + Hash + Debug {
+    /// `self + delta`, or `None` if that doesn't fit in `Self`.
+    fn checked_offset(self, delta: isize) -> Option<Self>;
+
+    /// `self + delta`, wrapped around into `0..size`.
+    fn wrapping_offset(self, delta: isize, size: Self) -> Self;
+
+    /// How far apart `self` and `other` are.
+    fn distance(self, other: Self) -> usize;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                fn checked_offset(self, delta: isize) -> Option<Self> {
+                    <$t>::try_from(self as i128 + delta as i128).ok()
+                }
+
+                fn wrapping_offset(self, delta: isize, size: Self) -> Self {
+                    (self as i128 + delta as i128).rem_euclid(size as i128) as $t
+                }
+
+                fn distance(self, other: Self) -> usize {
+                    (self as i128 - other as i128).unsigned_abs() as usize
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(usize, u32, u64, isize, i32, i64);
+
+/// One of the four orthogonal directions, with up being towards smaller `y`
+/// like in a [`Grid`](crate::grid::Grid).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Self; 4] = [Up, Down, Left, Right];
+
+    /// The `(dy, dx)` of one step this way.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Up => (-1, 0),
+            Down => (1, 0),
+            Left => (0, -1),
+            Right => (0, 1),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().reverse()
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Up => Down,
+            Down => Up,
+            Left => Right,
+            Right => Left,
+        }
+    }
+
+    /// Reads `^`, `v`, `<` or `>`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Up),
+            'v' => Some(Down),
+            '<' => Some(Left),
+            '>' => Some(Right),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Up => '^',
+            Down => 'v',
+            Left => '<',
+            Right => '>',
+        }
+    }
+}
+
+/// A point on a plane, with `y` growing downwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub y: T,
+    pub x: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(y: T, x: T) -> Self {
+        Self { y, x }
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    /// Moves by `(dy, dx)`, or returns `None` if that leaves `T`'s range.
+    pub fn checked_offset(self, (dy, dx): (isize, isize)) -> Option<Self> {
+        Some(Self {
+            y: self.y.checked_offset(dy)?,
+            x: self.x.checked_offset(dx)?,
+        })
+    }
+
+    /// Moves by `(dy, dx)`, panicking if that leaves `T`'s range.
+    pub fn offset(self, delta: (isize, isize)) -> Self {
+        self.checked_offset(delta)
+            .unwrap_or_else(|| panic!("{self:?} moved by {delta:?} out of range"))
+    }
+
+    /// Moves by `(dy, dx)` on a torus of `size`, so that leaving one edge
+    /// comes back in at the opposite one.
+    pub fn wrapping_offset(self, (dy, dx): (isize, isize), size: Self) -> Self {
+        Self {
+            y: self.y.wrapping_offset(dy, size.y),
+            x: self.x.wrapping_offset(dx, size.x),
+        }
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        self.offset(direction.offset())
+    }
+
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        self.checked_offset(direction.offset())
+    }
+
+    pub fn wrapping_step(self, direction: Direction, size: Self) -> Self {
+        self.wrapping_offset(direction.offset(), size)
+    }
+
+    /// The orthogonally adjacent points, in the order of [`Direction::ALL`].
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction))
+    }
+
+    /// The adjacent points, diagonals included, row by row.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dy, dx)))
+            .filter(|&delta| delta != (0, 0))
+            .filter_map(move |delta| self.checked_offset(delta))
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.y.distance(other.y) + self.x.distance(other.x)
+    }
+
+    pub fn chebyshev(self, other: Self) -> usize {
+        self.y.distance(other.y).max(self.x.distance(other.x))
+    }
+}
+
+impl<T: Neg<Output = T>> Point2<T> {
+    /// Rotates a quarter turn clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Self {
+            y: self.x,
+            x: -self.y,
+        }
+    }
+
+    /// Rotates a quarter turn counterclockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Self {
+            y: -self.x,
+            x: self.y,
+        }
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.y, self.x)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((y, x): (T, T)) -> Self {
+        Self { y, x }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(Point2 { y, x }: Point2<T>) -> Self {
+        (y, x)
+    }
+}
+
+/// A point in space.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub z: T,
+    pub y: T,
+    pub x: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(z: T, y: T, x: T) -> Self {
+        Self { z, y, x }
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.z, self.y, self.x)
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    /// Moves by `(dz, dy, dx)`, or returns `None` if that leaves `T`'s range.
+    pub fn checked_offset(self, (dz, dy, dx): (isize, isize, isize)) -> Option<Self> {
+        Some(Self {
+            z: self.z.checked_offset(dz)?,
+            y: self.y.checked_offset(dy)?,
+            x: self.x.checked_offset(dx)?,
+        })
+    }
+
+    /// The points sharing a face with this one.
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ]
+        .into_iter()
+        .filter_map(move |delta| self.checked_offset(delta))
+    }
+
+    /// The points sharing a face, edge or corner with this one.
+    pub fn neighbors26(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|dz| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| (dz, dy, dx))))
+            .filter(|&delta| delta != (0, 0, 0))
+            .filter_map(move |delta| self.checked_offset(delta))
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.z.distance(other.z) + self.y.distance(other.y) + self.x.distance(other.x)
+    }
+
+    pub fn chebyshev(self, other: Self) -> usize {
+        self.z
+            .distance(other.z)
+            .max(self.y.distance(other.y))
+            .max(self.x.distance(other.x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsigned_moves_stop_at_zero_or_wrap() {
+        let corner = Point2::new(0_usize, 0);
+
+        assert_eq!(corner.checked_step(Up), None);
+        assert_eq!(corner.step(Down).step(Right), Point2::new(1, 1));
+        assert_eq!(
+            corner.wrapping_step(Left, Point2::new(4, 6)),
+            Point2::new(0, 5)
+        );
+        assert_eq!(
+            corner.neighbors4().collect::<Vec<_>>(),
+            [Point2::new(1, 0), Point2::new(0, 1)]
+        );
+        assert_eq!(corner.neighbors8().count(), 3);
+    }
+
+    #[test]
+    fn turning_and_rotating_agree() {
+        for direction in Direction::ALL {
+            let (y, x) = direction.offset();
+            let right = Point2::new(y, x).rotate_right();
+            let left = Point2::new(y, x).rotate_left();
+
+            assert_eq!((right.y, right.x), direction.turn_right().offset());
+            assert_eq!((left.y, left.x), direction.turn_left().offset());
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(Direction::from_arrow(direction.arrow()), Some(direction));
+        }
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point2::new(-2_isize, 3);
+        let b = Point2::new(1, -1);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.to_string(), "(-2, 3)");
+
+        let origin = Point3::new(0_isize, 0, 0);
+        assert_eq!(origin.neighbors6().count(), 6);
+        assert_eq!(origin.neighbors26().count(), 26);
+        assert!(origin
+            .neighbors26()
+            .all(|point| origin.chebyshev(point) == 1));
+        assert_eq!(origin.manhattan(Point3::new(1, -2, 3)), 6);
+    }
+}
@@ -0,0 +1,302 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::Point2;
+use crate::parse::ParseError;
+
+/// A `(y, x)` position in a [`Grid`], with `y` growing downwards.
+pub type Position = (usize, usize);
+
+/// A rectangular grid of tiles, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    columns: usize,
+    tiles: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, columns: usize, fill: T) -> Self {
+        Self {
+            rows,
+            columns,
+            tiles: vec![fill; rows * columns],
+        }
+    }
+
+    /// Lays out tiles at points on an unbounded plane, in the smallest grid
+    /// that holds them all, with `fill` everywhere else. Later tiles on the
+    /// same point replace earlier ones.
+    pub fn from_points(points: impl IntoIterator<Item = (Point2<isize>, T)>, fill: T) -> Self {
+        let points: Vec<_> = points.into_iter().collect();
+        let (Some(top), Some(bottom)) = (
+            points.iter().map(|(point, _)| point.y).min(),
+            points.iter().map(|(point, _)| point.y).max(),
+        ) else {
+            return Self::new(0, 0, fill);
+        };
+        let left = points.iter().map(|(point, _)| point.x).min().unwrap();
+        let right = points.iter().map(|(point, _)| point.x).max().unwrap();
+
+        let mut grid = Self::new(bottom.abs_diff(top) + 1, right.abs_diff(left) + 1, fill);
+        for (point, tile) in points {
+            grid[(point.y.abs_diff(top), point.x.abs_diff(left))] = tile;
+        }
+
+        grid
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid by calling `tile` for each position, row by row.
+    pub fn from_fn(rows: usize, columns: usize, mut tile: impl FnMut(Position) -> T) -> Self {
+        Self {
+            rows,
+            columns,
+            tiles: (0..rows)
+                .flat_map(|y| (0..columns).map(move |x| (y, x)))
+                .map(&mut tile)
+                .collect(),
+        }
+    }
+
+    /// Parses a map with one character per tile.
+    ///
+    /// Rows shorter than the longest are padded as if they ended in spaces, so
+    /// `tile` decides whether ragged maps are allowed. `expected` describes
+    /// the accepted characters for the error message.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut tile: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let columns = input.lines().map(|line| line.chars().count()).max();
+        let columns = columns.ok_or_else(|| ParseError::new(input, expected))?;
+
+        let mut rows = 0;
+        let mut tiles = vec![];
+        for line in input.lines() {
+            for (i, c) in line.char_indices() {
+                tiles.push(tile(c).ok_or_else(|| ParseError::new(&line[i..], expected))?);
+            }
+
+            let end = &line[line.len()..];
+            for _ in line.chars().count()..columns {
+                tiles.push(tile(' ').ok_or_else(|| ParseError::new(end, expected))?);
+            }
+
+            rows += 1;
+        }
+
+        Ok(Self {
+            rows,
+            columns,
+            tiles,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn in_bounds(&self, (y, x): Position) -> bool {
+        y < self.rows && x < self.columns
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.in_bounds(position)
+            .then(|| &self.tiles[self.offset(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.in_bounds(position) {
+            let offset = self.offset(position);
+            Some(&mut self.tiles[offset])
+        } else {
+            None
+        }
+    }
+
+    /// Like [`get`](Self::get), for signed coordinates that may have walked
+    /// off the top or left edge.
+    pub fn get_signed(&self, (y, x): (isize, isize)) -> Option<&T> {
+        self.get((usize::try_from(y).ok()?, usize::try_from(x).ok()?))
+    }
+
+    fn offset(&self, (y, x): Position) -> usize {
+        y * self.columns + x
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.tiles[y * self.columns..(y + 1) * self.columns]
+    }
+
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.tiles.chunks(self.columns.max(1))
+    }
+
+    pub fn column(
+        &self,
+        x: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone {
+        self.tiles.iter().skip(x).step_by(self.columns.max(1))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let columns = self.columns;
+        (0..self.rows).flat_map(move |y| (0..columns).map(move |x| (y, x)))
+    }
+
+    /// Every tile along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.tiles)
+    }
+
+    /// The up to four orthogonally adjacent positions that are in bounds, in
+    /// the order of [`Direction::ALL`](crate::geometry::Direction::ALL).
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Point2::from(position)
+            .neighbors4()
+            .map(Position::from)
+            .filter(|&position| self.in_bounds(position))
+    }
+
+    /// The up to eight adjacent positions, diagonals included, that are in
+    /// bounds.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Point2::from(position)
+            .neighbors8()
+            .map(Position::from)
+            .filter(|&position| self.in_bounds(position))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            columns: self.columns,
+            tiles: self.tiles.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid with `tile` choosing each character.
+    pub fn display<F: Fn(&T) -> char>(&self, tile: F) -> GridDisplay<'_, T, F> {
+        GridDisplay { grid: self, tile }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        assert!(self.in_bounds(position), "{position:?} is out of bounds");
+        &self.tiles[self.offset(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        assert!(self.in_bounds(position), "{position:?} is out of bounds");
+        let offset = self.offset(position);
+        &mut self.tiles[offset]
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &Self::Output {
+        &self[Position::from(point)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut Self::Output {
+        &mut self[Position::from(point)]
+    }
+}
+
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.display(|&tile| tile.into()).fmt(f)
+    }
+}
+
+/// A [`Grid`] drawn with a custom tile mapping; see [`Grid::display`].
+pub struct GridDisplay<'a, T, F> {
+    grid: &'a Grid<T>,
+    tile: F,
+}
+
+impl<T, F: Fn(&T) -> char> Display for GridDisplay<'_, T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.grid.iter_rows() {
+            let row: String = row.iter().map(&self.tile).collect();
+            writeln!(f, "{row}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parses_and_renders() {
+        let grid = digits("123\n456\n").unwrap();
+
+        assert_eq!((grid.rows(), grid.columns()), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(
+            grid.display(|n| char::from_digit(n % 2, 10).unwrap())
+                .to_string(),
+            "101\n010\n"
+        );
+    }
+
+    #[test]
+    fn rejects_ragged_rows_unless_spaces_are_tiles() {
+        let input = "123\n45\n";
+        let err = digits(input).unwrap_err().locate(8, input);
+        assert_eq!(err.position(), Some((2, 3)));
+
+        let grid = Grid::parse(input, "a digit or space", |c| match c {
+            ' ' => Some(None),
+            c => c.to_digit(10).map(Some),
+        })
+        .unwrap();
+        assert_eq!(grid[(1, 2)], None);
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::new(3, 3, ());
+
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn lays_out_points_in_their_bounding_box() {
+        let points = [(Point2::new(-1, 2), 'a'), (Point2::new(1, 0), 'b')];
+        let grid = Grid::from_points(points, '.');
+
+        assert_eq!(grid.to_string(), "..a\n...\nb..\n");
+        assert_eq!(Grid::from_points([], '.').rows(), 0);
+    }
+}
@@ -0,0 +1,331 @@
+use std::fmt::Display;
+use std::fs::{create_dir_all, write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::geometry::Point2;
+use crate::grid::Grid;
+
+/// A color, as red, green and blue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+
+    /// The color `fraction` of the way from `from` to `to`, with `fraction`
+    /// clamped to `0.0..=1.0`.
+    pub fn blend(from: Self, to: Self, fraction: f64) -> Self {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let channel = |from: u8, to: u8| {
+            (f64::from(from) + (f64::from(to) - f64::from(from)) * fraction).round() as u8
+        };
+
+        Self(
+            channel(from.0, to.0),
+            channel(from.1, to.1),
+            channel(from.2, to.2),
+        )
+    }
+}
+
+impl Display for Rgb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// An image file format the rasterizer can write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+            Format::Svg => "svg",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ppm" => Ok(Format::Ppm),
+            "png" => Ok(Format::Png),
+            "svg" => Ok(Format::Svg),
+            _ => Err(format!("expected `ppm`, `png` or `svg`, got `{s}`")),
+        }
+    }
+}
+
+/// A picture with one pixel per tile, blown up by a scale when it's encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Colors each of the grid's tiles with `palette`.
+    pub fn from_grid<T>(grid: &Grid<T>, palette: impl Fn(&T) -> Rgb) -> Self {
+        Self {
+            width: grid.columns(),
+            height: grid.rows(),
+            pixels: grid.iter().map(|(_, tile)| palette(tile)).collect(),
+        }
+    }
+
+    /// Colors points on an unbounded plane, in the smallest image that holds
+    /// them all, with `background` everywhere else.
+    pub fn from_points(
+        points: impl IntoIterator<Item = (Point2<isize>, Rgb)>,
+        background: Rgb,
+    ) -> Self {
+        Self::from_grid(&Grid::from_points(points, background), |&color| color)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn encode(&self, format: Format, scale: usize) -> Vec<u8> {
+        match format {
+            Format::Ppm => self.ppm(scale),
+            Format::Png => self.png(scale),
+            Format::Svg => self.svg(scale).into_bytes(),
+        }
+    }
+
+    fn row(&self, y: usize) -> &[Rgb] {
+        &self.pixels[y * self.width..(y + 1) * self.width]
+    }
+
+    // Each scaled row of pixels as red, green and blue bytes
+    fn scaled_rows(&self, scale: usize) -> impl Iterator<Item = Vec<u8>> + '_ {
+        (0..self.height * scale).map(move |y| {
+            self.row(y / scale)
+                .iter()
+                .flat_map(|&Rgb(r, g, b)| [r, g, b].repeat(scale))
+                .collect()
+        })
+    }
+
+    fn ppm(&self, scale: usize) -> Vec<u8> {
+        let mut bytes =
+            format!("P6\n{} {}\n255\n", self.width * scale, self.height * scale).into_bytes();
+        for row in self.scaled_rows(scale) {
+            bytes.extend(row);
+        }
+
+        bytes
+    }
+
+    fn png(&self, scale: usize) -> Vec<u8> {
+        let mut header = vec![];
+        header.extend(u32_be(self.width * scale));
+        header.extend(u32_be(self.height * scale));
+        // 8 bits per channel, RGB, no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+
+        // Every row starts with filter type 0, meaning no filter
+        let mut scanlines = vec![];
+        for row in self.scaled_rows(scale) {
+            scanlines.push(0);
+            scanlines.extend(row);
+        }
+
+        let mut bytes = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut bytes, b"IHDR", &header);
+        png_chunk(&mut bytes, b"IDAT", &zlib_stored(&scanlines));
+        png_chunk(&mut bytes, b"IEND", &[]);
+        bytes
+    }
+
+    // One rectangle per run of same colored pixels in a row, sized in tiles
+    // and scaled by the viewport
+    fn svg(&self, scale: usize) -> String {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+            self.width * scale,
+            self.height * scale,
+            self.width,
+            self.height,
+        );
+
+        for y in 0..self.height {
+            let row = self.row(y);
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a == b) {
+                svg += &format!(
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{}\" height=\"1\" fill=\"{}\"/>\n",
+                    run.len(),
+                    run[0]
+                );
+                x += run.len();
+            }
+        }
+
+        svg + "</svg>\n"
+    }
+}
+
+fn u32_be(n: usize) -> [u8; 4] {
+    u32::try_from(n)
+        .expect("image too large for PNG")
+        .to_be_bytes()
+}
+
+fn png_chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend(u32_be(data.len()));
+    let start = bytes.len();
+    bytes.extend(kind);
+    bytes.extend(data);
+
+    let crc = crc32(&bytes[start..]);
+    bytes.extend(crc.to_be_bytes());
+}
+
+// A zlib stream of uncompressed deflate blocks, which every PNG reader
+// accepts, so no compressor is needed
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        bytes.extend([1, 0, 0, 0xff, 0xff]);
+    }
+
+    while let Some(block) = blocks.next() {
+        let last = u8::from(blocks.peek().is_none());
+        let len = block.len() as u16;
+        bytes.push(last);
+        bytes.extend(len.to_le_bytes());
+        bytes.extend((!len).to_le_bytes());
+        bytes.extend(block);
+    }
+
+    bytes.extend(adler32(data).to_be_bytes());
+    bytes
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % 65_521;
+        b = (b + a) % 65_521;
+    }
+
+    (b << 16) | a
+}
+
+/// Where exported images go, and how they're written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Export {
+    pub directory: PathBuf,
+    pub format: Format,
+    /// How many pixels wide and tall each tile is drawn
+    pub scale: usize,
+}
+
+static EXPORT: OnceLock<Export> = OnceLock::new();
+
+/// Turns image export on for the rest of the process. Only the first call
+/// has any effect.
+pub fn enable(export: Export) {
+    let _ = EXPORT.set(export);
+}
+
+pub fn enabled() -> bool {
+    EXPORT.get().is_some()
+}
+
+/// Writes the image drawn by `image` to `dayNN-<name>.<extension>`, if image
+/// export is on. Failures are reported on stderr rather than stopping the
+/// solution.
+pub fn export(day: u8, name: &str, image: impl FnOnce() -> Image) {
+    let Some(export) = EXPORT.get() else {
+        return;
+    };
+
+    let path = export
+        .directory
+        .join(format!("day{day:02}-{name}.{}", export.format.extension()));
+    let bytes = image().encode(export.format, export.scale);
+
+    if let Err(err) = create_dir_all(&export.directory).and_then(|()| write(&path, bytes)) {
+        eprintln!("couldn't write {}: {err}", path.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Image {
+        let grid = Grid::from_fn(1, 2, |(_, x)| x == 0);
+        Image::from_grid(&grid, |&black| if black { Rgb::BLACK } else { Rgb::WHITE })
+    }
+
+    #[test]
+    fn encodes_ppm_and_svg() {
+        let image = checkerboard();
+
+        assert_eq!(
+            image.encode(Format::Ppm, 1),
+            b"P6\n2 1\n255\n\x00\x00\x00\xff\xff\xff"
+        );
+        assert_eq!(image.encode(Format::Ppm, 2).len(), 11 + 4 * 2 * 3);
+
+        let svg = String::from_utf8(image.encode(Format::Svg, 3)).unwrap();
+        assert!(svg.contains("width=\"6\" height=\"3\" viewBox=\"0 0 2 1\""));
+        assert!(svg.contains("<rect x=\"1\" y=\"0\" width=\"1\" height=\"1\" fill=\"#ffffff\"/>"));
+    }
+
+    #[test]
+    fn encodes_png_with_valid_checksums() {
+        let png = checkerboard().encode(Format::Png, 1);
+
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        // Every IEND chunk is the same, CRC included
+        assert!(png.ends_with(b"\x00\x00\x00\x00IEND\xae\x42\x60\x82"));
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn blends_and_prints_colors() {
+        assert_eq!(Rgb::blend(Rgb::BLACK, Rgb::WHITE, 0.5), Rgb(128, 128, 128));
+        assert_eq!(Rgb::blend(Rgb::BLACK, Rgb::WHITE, 2.0), Rgb::WHITE);
+        assert_eq!(Rgb(255, 16, 0).to_string(), "#ff1000");
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::Display;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Reads a standalone day binary's puzzle input at runtime.
+///
+/// See [`InputSource::from_args`] for how the input is picked. Named inputs
+/// are looked up next to the calling crate's `Cargo.toml`.
+#[macro_export]
+macro_rules! read_input {
+    () => {{
+        let source = $crate::input::InputSource::from_args();
+        source
+            .read(env!("CARGO_MANIFEST_DIR"))
+            .unwrap_or_else(|err| panic!("couldn't read {source}: {err}"))
+    }};
+}
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's `input.txt`
+    Real,
+    /// The day's `test_input<number>.txt`, or `test_input.txt` without a number
+    Test(Option<u8>),
+    /// Any file on disk
+    Path(PathBuf),
+    /// Standard input
+    Stdin,
+}
+
+impl InputSource {
+    /// Picks the input for a standalone day binary.
+    ///
+    /// A path argument wins (`-` reads stdin), then `TEST=<number>` selects a
+    /// test fixture (any other value selects the unnumbered one), and the
+    /// real input is used otherwise.
+    pub fn from_args() -> Self {
+        if let Some(path) = std::env::args_os().nth(1) {
+            return Self::from_path(path.into());
+        }
+
+        match std::env::var("TEST") {
+            Ok(number) => Self::Test(number.parse().ok()),
+            Err(_) => Self::Real,
+        }
+    }
+
+    pub fn from_path(path: PathBuf) -> Self {
+        if path.as_os_str() == "-" {
+            Self::Stdin
+        } else {
+            Self::Path(path)
+        }
+    }
+
+    /// Reads the input, resolving named inputs relative to `directory`.
+    pub fn read(&self, directory: impl AsRef<Path>) -> io::Result<String> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            source => read_to_string(source.path(directory.as_ref())),
+        }
+    }
+
+    /// Where the input is read from, resolving named inputs relative to
+    /// `directory`.
+    pub fn path(&self, directory: &Path) -> PathBuf {
+        match self {
+            Self::Real => directory.join("input.txt"),
+            Self::Test(number) => fixture(directory, *number),
+            Self::Path(path) => path.clone(),
+            Self::Stdin => PathBuf::from("-"),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Real => write!(f, "input.txt"),
+            Self::Test(None) => write!(f, "test_input.txt"),
+            Self::Test(Some(number)) => write!(f, "test_input{number}.txt"),
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+// Days with a single fixture don't number it, so it's also fixture 1, and
+// the first of a day's numbered fixtures is also the unnumbered one. Any
+// other number has to be there, so a wrong one can't quietly read another
+fn fixture(directory: &Path, number: Option<u8>) -> PathBuf {
+    let numbered = directory.join(format!("test_input{}.txt", number.unwrap_or(1)));
+    let unnumbered = directory.join("test_input.txt");
+
+    match number {
+        Some(1) if !numbered.exists() && unnumbered.exists() => unnumbered,
+        Some(_) => numbered,
+        None if unnumbered.exists() || !numbered.exists() => unnumbered,
+        None => numbered,
+    }
+}
+
+/// The directory holding a day's crate and its inputs.
+pub fn day_directory(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day-{day:02}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_fixtures_by_number() {
+        let file = |day, number| {
+            let path = InputSource::Test(number).path(&day_directory(day));
+            path.file_name().unwrap().to_string_lossy().into_owned()
+        };
+
+        assert_eq!(file(2, None), "test_input.txt");
+        assert_eq!(file(2, Some(1)), "test_input.txt");
+        assert_eq!(file(9, None), "test_input1.txt");
+        assert_eq!(file(9, Some(2)), "test_input2.txt");
+
+        let missing = InputSource::Test(Some(2));
+        assert_eq!(file(2, Some(2)), "test_input2.txt");
+        assert!(missing.read(day_directory(2)).is_err());
+    }
+}
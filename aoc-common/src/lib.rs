@@ -0,0 +1,20 @@
+//! Code shared between the day crates.
+
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod bitset;
+pub mod config;
+pub mod cycle;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod numeric;
+pub mod parse;
+pub mod pool;
+pub mod property;
+pub mod search;
+pub mod solution;
+pub mod trace;
@@ -0,0 +1,205 @@
+use std::fmt::Debug;
+use std::ops::{Div, Rem};
+
+/// A primitive integer type, for the functions in this module.
+pub trait Integer: Copy + Ord + Debug + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// The remainder that's never negative, for a positive `modulus`.
+    fn rem_euclid(self, modulus: Self) -> Self;
+
+    fn abs(self) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn rem_euclid(self, modulus: Self) -> Self {
+                    <$t>::rem_euclid(self, modulus)
+                }
+
+                #[allow(unused_comparisons)]
+                fn abs(self) -> Self {
+                    if self < 0 {
+                        Self::ZERO - self
+                    } else {
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// `a + b`, panicking if it overflows, even in release builds, where `+`
+/// would wrap around instead.
+pub fn add<T: Integer>(a: T, b: T) -> T {
+    a.checked_add(b)
+        .unwrap_or_else(|| panic!("{a:?} + {b:?} overflows"))
+}
+
+/// `a - b`, panicking if it overflows, even in release builds.
+pub fn sub<T: Integer>(a: T, b: T) -> T {
+    a.checked_sub(b)
+        .unwrap_or_else(|| panic!("{a:?} - {b:?} overflows"))
+}
+
+/// `a * b`, panicking if it overflows, even in release builds.
+pub fn mul<T: Integer>(a: T, b: T) -> T {
+    a.checked_mul(b)
+        .unwrap_or_else(|| panic!("{a:?} * {b:?} overflows"))
+}
+
+/// The greatest common divisor, which is never negative.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple, panicking if it doesn't fit in `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+
+    mul(a.abs() / gcd(a, b), b.abs())
+}
+
+/// The least common multiple of all of `numbers`, or 1 if there are none.
+pub fn lcm_of<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// `value` wrapped around into `0..modulus`, for moving around a cycle of
+/// `modulus` places by a distance that can be negative or many times round.
+pub fn wrap<T: Integer>(value: T, modulus: T) -> T {
+    value.rem_euclid(modulus)
+}
+
+/// `a * b` modulo `modulus`, without overflowing however big `a * b` is.
+pub fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (u128::from(a) * u128::from(b) % u128::from(modulus)) as u64
+}
+
+/// `a + b` modulo `modulus`, without overflowing however big `a + b` is.
+pub fn add_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((u128::from(a) + u128::from(b)) % u128::from(modulus)) as u64
+}
+
+/// The `x` with `a * x` equal to 1 modulo `modulus`, if there is one.
+pub fn inverse_mod(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(i128::from(a), i128::from(modulus));
+    (g == 1).then(|| wrap(x, i128::from(modulus)) as i64)
+}
+
+// The gcd, along with `x` and `y` such that `a * x + b * y` is the gcd
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a.abs(), a.signum(), 0);
+    }
+
+    let (g, x, y) = extended_gcd(b, a.rem_euclid(b));
+    (g, y, x - a.div_euclid(b) * y)
+}
+
+/// Solves the Chinese remainder theorem: the smallest `x` that's `residue`
+/// modulo `modulus` for every pair, along with the lcm of the moduli, which
+/// every other solution differs from it by a multiple of.
+///
+/// The moduli needn't be coprime, but then there's only a solution if the
+/// residues agree wherever the moduli share a factor.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut solution: (i128, i128) = (0, 1);
+
+    for &(residue, modulus) in congruences {
+        let (x, m) = solution;
+        let (r, n) = (
+            wrap(i128::from(residue), i128::from(modulus)),
+            i128::from(modulus),
+        );
+
+        // x + m * k = r (mod n), so m * k = r - x (mod n)
+        let (g, inverse, _) = extended_gcd(m, n);
+        if (r - x) % g != 0 {
+            return None;
+        }
+
+        let step = n / g;
+        let k = wrap((r - x) / g % step * inverse % step, step);
+        let lcm = m * step;
+        solution = (wrap(x + m * k, lcm), lcm);
+    }
+
+    let (x, m) = solution;
+    Some((i64::try_from(x).ok()?, i64::try_from(m).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12_u64, 18), 6);
+        assert_eq!(gcd(-12_i64, 18), 6);
+        assert_eq!(gcd(0_u8, 7), 7);
+        assert_eq!(lcm(4_usize, 6), 12);
+        assert_eq!(lcm_of([23_u64, 19, 13, 17]), 96577);
+        assert_eq!(lcm_of([2_u64, 4, 8]), 8);
+        assert_eq!(lcm_of(Vec::<u32>::new()), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn lcm_checks_for_overflow() {
+        lcm(251_u8, 241);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(wrap(-1_i64, 5), 4);
+        assert_eq!(wrap(-11_i64, 5), 4);
+        assert_eq!(wrap(12_usize, 5), 2);
+        assert_eq!(mul_mod(u64::MAX, u64::MAX, 1_000_000_007), 114_944_269);
+        assert_eq!(add_mod(u64::MAX, 1, 10), 6);
+        assert_eq!(inverse_mod(3, 7), Some(5));
+        assert_eq!(inverse_mod(4, 8), None);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(-1, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+}
@@ -0,0 +1,345 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Why and where a puzzle input failed to parse.
+///
+/// Parsers build it from the unparsed rest of their input, which must be a
+/// slice of the puzzle input. [`solve`](crate::solution::solve) then works out
+/// the line and column from where that slice starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    expected: String,
+    found: String,
+    address: usize,
+    width: usize,
+    day: Option<u8>,
+    position: Option<(usize, usize)>,
+}
+
+impl ParseError {
+    /// An error at the start of `rest`, which should have begun with
+    /// `expected`.
+    pub fn new(rest: &str, expected: impl Into<String>) -> Self {
+        let (found, width) = token(rest);
+        Self {
+            expected: expected.into(),
+            found,
+            address: rest.as_ptr() as usize,
+            width,
+            day: None,
+            position: None,
+        }
+    }
+
+    /// Records the day, and the line and column if the error points into
+    /// `input`.
+    pub fn locate(self, day: u8, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let position = (start..=start + input.len())
+            .contains(&self.address)
+            .then(|| {
+                let before = &input[..self.address - start];
+                let line = before.matches('\n').count() + 1;
+                let column = before.rsplit('\n').next().unwrap().chars().count() + 1;
+                (line, column)
+            });
+
+        Self {
+            day: Some(day),
+            position,
+            ..self
+        }
+    }
+
+    /// The 1-based line and column, once located.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        self.position
+    }
+
+    /// The line of `input` the error is on, with what was found there
+    /// underlined, once located.
+    pub fn underline(&self, input: &str) -> Option<String> {
+        let (line, column) = self.position?;
+        let text = input.lines().nth(line - 1).unwrap_or_default();
+        let marks = "^".repeat(self.width.max(1));
+        Some(format!("{text}\n{:width$}{marks}", "", width = column - 1))
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day:02}, ")?;
+        }
+        if let Some((line, column)) = self.position {
+            write!(f, "line {line}, column {column}: ")?;
+        }
+        write!(f, "expected {}, found {}", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Describes what's at the start of `rest`: a whole word or number, or else a
+/// single character, and how many characters wide it is.
+fn token(rest: &str) -> (String, usize) {
+    match rest.chars().next() {
+        None | Some('\n') => ("end of line".into(), 0),
+        Some(c) if c.is_alphanumeric() => {
+            let word = rest.split(|c: char| !c.is_alphanumeric()).next().unwrap();
+            (format!("{word:?}"), word.chars().count())
+        }
+        Some(c) => (format!("{:?}", c.to_string()), 1),
+    }
+}
+
+/// Strips `prefix` off the front of `s`.
+pub fn prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(s, format!("{prefix:?}")))
+}
+
+/// Splits `s` around the first `delimiter`.
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(&s[s.len()..], format!("{delimiter:?}")))
+}
+
+/// Parses all of `s` as a number.
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(s, "a number"))
+}
+
+/// Parses a `T` off the front of `input`, moving `input` past what it used.
+///
+/// Any function or closure from `&mut &str` is one, so parsers can be written
+/// as straight-line code with `?`, and the functions below make the common
+/// ones.
+pub trait Parser<'a, T> {
+    fn parse_next(&mut self, input: &mut &'a str) -> Result<T, ParseError>;
+
+    /// Parses all of `input`, failing if anything's left over.
+    fn parse_all(&mut self, input: &'a str) -> Result<T, ParseError> {
+        let mut rest = input;
+        let value = self.parse_next(&mut rest)?;
+        end(&mut rest)?;
+        Ok(value)
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: FnMut(&mut &'a str) -> Result<T, ParseError>,
+{
+    fn parse_next(&mut self, input: &mut &'a str) -> Result<T, ParseError> {
+        self(input)
+    }
+}
+
+/// Exactly `text`, or else an error at the word where the input stops
+/// matching it.
+pub fn literal<'a>(text: &str) -> impl FnMut(&mut &'a str) -> Result<&'a str, ParseError> + '_ {
+    move |input: &mut &'a str| {
+        let Some(rest) = input.strip_prefix(text) else {
+            let matching = input
+                .char_indices()
+                .zip(text.chars())
+                .find(|((_, found), expected)| found != expected)
+                .map_or(input.len().min(text.len()), |((i, _), _)| i);
+            let word = input[..matching]
+                .char_indices()
+                .rfind(|(_, c)| !c.is_alphanumeric())
+                .map_or(0, |(i, c)| i + c.len_utf8());
+            return Err(ParseError::new(&input[word..], format!("{text:?}")));
+        };
+
+        let matched = &input[..text.len()];
+        *input = rest;
+        Ok(matched)
+    }
+}
+
+/// An integer of type `T`, with a sign if it has one.
+pub fn integer<T: FromStr>(input: &mut &str) -> Result<T, ParseError> {
+    let sign = usize::from(input.starts_with(['-', '+']));
+    let digits = input[sign..]
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len() - sign);
+    let (text, rest) = input.split_at(sign + digits);
+
+    let value = text
+        .parse()
+        .map_err(|_| ParseError::new(input, "a number"))?;
+    *input = rest;
+    Ok(value)
+}
+
+/// A run of letters and digits.
+pub fn word<'a>(input: &mut &'a str) -> Result<&'a str, ParseError> {
+    let length = input
+        .find(|c: char| !c.is_alphanumeric())
+        .unwrap_or(input.len());
+    if length == 0 {
+        return Err(ParseError::new(input, "a word"));
+    }
+
+    let (word, rest) = input.split_at(length);
+    *input = rest;
+    Ok(word)
+}
+
+/// Nothing: the end of the input, or of the line it's been given.
+pub fn end(input: &mut &str) -> Result<(), ParseError> {
+    match input.is_empty() {
+        true => Ok(()),
+        false => Err(ParseError::new(input, "end of line")),
+    }
+}
+
+/// `label` and then whatever `value` parses, such as `"Test: divisible by "`
+/// and a number.
+pub fn labeled<'a, 'p, T>(
+    label: &'p str,
+    mut value: impl Parser<'a, T> + 'p,
+) -> impl FnMut(&mut &'a str) -> Result<T, ParseError> + 'p {
+    let mut label = literal(label);
+    move |input: &mut &'a str| {
+        label.parse_next(input)?;
+        value.parse_next(input)
+    }
+}
+
+/// One or more of `item`, with `separator` between them.
+pub fn separated<'a, 'p, T>(
+    mut item: impl Parser<'a, T> + 'p,
+    separator: &'p str,
+) -> impl FnMut(&mut &'a str) -> Result<Vec<T>, ParseError> + 'p {
+    move |input: &mut &'a str| {
+        let mut items = vec![item.parse_next(input)?];
+        while let Some(rest) = input.strip_prefix(separator) {
+            *input = rest;
+            items.push(item.parse_next(input)?);
+        }
+
+        Ok(items)
+    }
+}
+
+/// Whatever `first` parses, or else whatever `second` does from the same
+/// place, failing with `second`'s error.
+pub fn either<'a, T>(
+    mut first: impl Parser<'a, T>,
+    mut second: impl Parser<'a, T>,
+) -> impl FnMut(&mut &'a str) -> Result<T, ParseError> {
+    move |input: &mut &'a str| {
+        let start = *input;
+        first.parse_next(input).or_else(|_| {
+            *input = start;
+            second.parse_next(input)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_errors_in_the_input() {
+        let input = "move 1 from 2 to 3\nmove 1 form 2 to 3\n";
+        let line = input.lines().nth(1).unwrap();
+        let rest = prefix(line, "move ").unwrap();
+        let (_count, rest) = split_once(rest, " ").unwrap();
+        let err = prefix(rest, "from ").unwrap_err().locate(5, input);
+
+        assert_eq!(err.position(), Some((2, 8)));
+        assert_eq!(
+            err.to_string(),
+            r#"day 05, line 2, column 8: expected "from ", found "form""#
+        );
+    }
+
+    #[test]
+    fn reports_missing_delimiters_at_the_end_of_the_line() {
+        let input = "1,2\n3";
+        let line = input.lines().nth(1).unwrap();
+        let err = split_once(line, ",").unwrap_err().locate(18, input);
+
+        assert_eq!(
+            err.to_string(),
+            r#"day 18, line 2, column 2: expected ",", found end of line"#
+        );
+    }
+
+    #[test]
+    fn errors_outside_the_input_have_no_position() {
+        let copy = String::from("x");
+        let err = number::<u8>(&copy).unwrap_err().locate(1, "1\n2\n");
+
+        assert_eq!(err.position(), None);
+        assert_eq!(err.to_string(), r#"day 01, expected a number, found "x""#);
+    }
+
+    #[test]
+    fn combines_parsers() {
+        let mut valve = |input: &mut &'static str| {
+            let name = labeled("Valve ", word).parse_next(input)?;
+            let rate: u32 = labeled(" has flow rate=", integer).parse_next(input)?;
+            either(
+                literal("; tunnels lead to valves "),
+                literal("; tunnel leads to valve "),
+            )
+            .parse_next(input)?;
+            let neighbors = separated(word, ", ").parse_next(input)?;
+            Ok((name, rate, neighbors))
+        };
+
+        assert_eq!(
+            valve.parse_all("Valve AA has flow rate=0; tunnels lead to valves DD, II"),
+            Ok(("AA", 0, vec!["DD", "II"]))
+        );
+        assert_eq!(
+            valve.parse_all("Valve JJ has flow rate=21; tunnel leads to valve II"),
+            Ok(("JJ", 21, vec!["II"]))
+        );
+        assert_eq!(
+            separated(integer::<i8>, ",").parse_all("-1,+2,3"),
+            Ok(vec![-1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn underlines_what_was_found() {
+        let input = "Sensor at x=2, y=18\nSensor at x=9, y=300\n";
+        let line = input.lines().nth(1).unwrap();
+        let mut sensor = |input: &mut &'static str| {
+            let x: u8 = labeled("Sensor at x=", integer).parse_next(input)?;
+            let y: u8 = labeled(", y=", integer).parse_next(input)?;
+            Ok((x, y))
+        };
+        let err = sensor.parse_all(line).unwrap_err().locate(15, input);
+
+        assert_eq!(
+            err.to_string(),
+            r#"day 15, line 2, column 18: expected a number, found "300""#
+        );
+        assert_eq!(
+            err.underline(input).unwrap(),
+            "Sensor at x=9, y=300\n                 ^^^"
+        );
+        assert_eq!(
+            sensor
+                .parse_all("Sensor at x=9, y=3 ")
+                .unwrap_err()
+                .to_string(),
+            r#"expected end of line, found " ""#
+        );
+        assert_eq!(
+            sensor
+                .parse_all("Sensor at x=9, z=3")
+                .unwrap_err()
+                .to_string(),
+            r#"expected ", y=", found "z""#
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+
+// How many threads run jobs at once, counting the ones that hand them out
+static THREADS: OnceLock<usize> = OnceLock::new();
+
+// How many more threads [`map`] can start before it's at `THREADS`
+static FREE: OnceLock<AtomicUsize> = OnceLock::new();
+
+/// Sets how many threads run jobs at once, which is otherwise one per CPU.
+/// Only the first call has any effect, and only before the first [`map`].
+pub fn set_threads(threads: usize) {
+    let _ = THREADS.set(threads.max(1));
+}
+
+pub fn threads() -> usize {
+    *THREADS.get_or_init(|| thread::available_parallelism().map_or(1, NonZeroUsize::get))
+}
+
+// A thread taken from the ones that are free, given back when it's dropped
+struct Lent;
+
+impl Lent {
+    fn take() -> Option<Self> {
+        let free = FREE.get_or_init(|| AtomicUsize::new(threads() - 1));
+        free.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .ok()
+            .map(|_| Lent)
+    }
+}
+
+impl Drop for Lent {
+    fn drop(&mut self) {
+        FREE.get().unwrap().fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Calls `f` on every item, on whichever of the threads are free as well as
+/// the calling one, and returns the results in the items' order.
+///
+/// The calling thread works through the items too, so a job can `map` over
+/// items of its own without waiting for a thread to come free.
+pub fn map<T, R>(items: impl IntoIterator<Item = T>, f: impl Fn(T) -> R + Sync) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+{
+    let items: Vec<T> = items.into_iter().collect();
+    let count = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results: Mutex<Vec<Option<R>>> = Mutex::new((0..count).map(|_| None).collect());
+
+    let work = || loop {
+        let Some((i, item)) = queue.lock().unwrap().next() else {
+            return;
+        };
+        let result = f(item);
+        results.lock().unwrap()[i] = Some(result);
+    };
+
+    thread::scope(|scope| {
+        let work = &work;
+        for _ in 1..count {
+            let Some(lent) = Lent::take() else {
+                break;
+            };
+            scope.spawn(move || {
+                let _lent = lent;
+                work();
+            });
+        }
+
+        work();
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(Option::unwrap)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order_when_jobs_map_too() {
+        let sums = map(1..=20_u64, |n| map(1..=n, |i| i * i).iter().sum::<u64>());
+        let expected: Vec<u64> = (1..=20).map(|n| n * (n + 1) * (2 * n + 1) / 6).collect();
+
+        assert_eq!(sums, expected);
+        assert_eq!(
+            FREE.get().unwrap().load(Ordering::SeqCst),
+            threads() - 1,
+            "every thread lent out should have been given back"
+        );
+    }
+}
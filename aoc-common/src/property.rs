@@ -0,0 +1,156 @@
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+
+/// How many inputs [`agree`] tries at each size.
+pub const SEEDS: u64 = 8;
+
+/// Checks that `left` and `right`, two ways of getting the same result from
+/// an input, agree on inputs generated for `S` at each of `sizes`.
+///
+/// Panics with the smallest input they disagree on: the first found going up
+/// through the sizes, then with lines taken out for as long as it still
+/// parses and they still disagree the same way. A panic in either one counts
+/// as disagreeing.
+pub fn agree<S, T>(
+    sizes: RangeInclusive<usize>,
+    left: impl Fn(&str) -> T,
+    right: impl Fn(&str) -> T,
+) where
+    S: Solution,
+    T: PartialEq + Debug,
+{
+    for size in sizes {
+        for seed in 0..SEEDS {
+            let input = S::generate(&mut Rng::new(seed), size);
+            let found = (outcome(&left, &input), outcome(&right, &input));
+            if found.0 == found.1 {
+                continue;
+            }
+
+            let same_way = |candidate: &str| {
+                S::parse(candidate).is_ok()
+                    && disagree_alike(
+                        &found,
+                        &(outcome(&left, candidate), outcome(&right, candidate)),
+                    )
+            };
+            let input = shrink(input, same_way);
+            let (left, right) = (outcome(&left, &input), outcome(&right, &input));
+
+            panic!(
+                "day {:02} implementations disagree on seed {seed}, size {size}, shrunk to\n{input}left:  {left:?}\nright: {right:?}",
+                S::DAY
+            );
+        }
+    }
+}
+
+/// The day's own answers to `input`, one per part, for comparing with
+/// another solver.
+pub fn answers<S: Solution>(input: &str) -> Vec<Answer> {
+    let parsed = S::parse(input).unwrap_or_else(|err| panic!("{}", err.locate(S::DAY, input)));
+    let part1 = S::part1(&parsed);
+    [part1].into_iter().chain(S::part2(&parsed)).collect()
+}
+
+// What `f` returns, or the message it panicked with
+fn outcome<T>(f: impl Fn(&str) -> T, input: &str) -> Result<T, String> {
+    catch_unwind(AssertUnwindSafe(|| f(input))).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default()
+    })
+}
+
+// Whether a smaller input goes wrong like the original did: the same side
+// panics, or neither does and the answers still differ
+fn disagree_alike<T: PartialEq>(
+    (left, right): &(Result<T, String>, Result<T, String>),
+    (smaller_left, smaller_right): &(Result<T, String>, Result<T, String>),
+) -> bool {
+    left.is_ok() == smaller_left.is_ok()
+        && right.is_ok() == smaller_right.is_ok()
+        && smaller_left != smaller_right
+}
+
+// Takes out one line at a time for as long as `keep` still holds, until no
+// single line can go
+fn shrink(mut input: String, keep: impl Fn(&str) -> bool) -> String {
+    'smaller: loop {
+        let lines: Vec<&str> = input.lines().collect();
+        for i in (0..lines.len()).rev() {
+            let candidate: String = lines
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, line)| format!("{line}\n"))
+                .collect();
+
+            if keep(&candidate) {
+                input = candidate;
+                continue 'smaller;
+            }
+        }
+
+        return input;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{number, ParseError};
+
+    struct Numbers;
+
+    impl Solution for Numbers {
+        const DAY: u8 = 0;
+
+        type Parsed<'input> = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            input.lines().map(number).collect()
+        }
+
+        fn part1(numbers: &Self::Parsed<'_>) -> Answer {
+            numbers.iter().sum::<u32>().into()
+        }
+
+        fn part2(_numbers: &Self::Parsed<'_>) -> Option<Answer> {
+            None
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> String {
+            (0..size)
+                .map(|_| format!("{}\n", rng.range(0..=9)))
+                .collect()
+        }
+    }
+
+    // Adds up every number but sevens
+    fn buggy(input: &str) -> Vec<Answer> {
+        let sum: u32 = Numbers::parse(input)
+            .unwrap()
+            .into_iter()
+            .filter(|&n| n != 7)
+            .sum();
+        vec![sum.into()]
+    }
+
+    #[test]
+    fn shrinks_to_the_line_that_matters() {
+        agree::<Numbers, _>(1..=5, answers::<Numbers>, answers::<Numbers>);
+
+        let message = catch_unwind(|| agree::<Numbers, _>(5..=5, answers::<Numbers>, buggy))
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap();
+        assert!(message.contains("shrunk to\n7\nleft:  Ok([Number(7)])\nright: Ok([Number(0)])"));
+    }
+}
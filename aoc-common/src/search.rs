@@ -0,0 +1,284 @@
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A graph where every step costs the same, given as each node's neighbors.
+///
+/// Closures from a node to its neighbors are graphs.
+pub trait Graph<N> {
+    fn neighbors(&self, node: &N) -> impl IntoIterator<Item = N>;
+}
+
+impl<N, F, I> Graph<N> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    fn neighbors(&self, node: &N) -> impl IntoIterator<Item = N> {
+        self(node)
+    }
+}
+
+/// A graph whose steps have costs, given as the edges out of each node.
+///
+/// Closures from a node to `(neighbor, cost)` pairs are weighted graphs.
+pub trait WeightedGraph<N> {
+    fn edges(&self, node: &N) -> impl IntoIterator<Item = (N, usize)>;
+}
+
+impl<N, F, I> WeightedGraph<N> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    fn edges(&self, node: &N) -> impl IntoIterator<Item = (N, usize)> {
+        self(node)
+    }
+}
+
+/// What a search found: the cheapest known cost to every node it reached, and
+/// the step into each one.
+///
+/// When a search stops at its goal, nodes it hadn't finished with may have a
+/// cost higher than their cheapest.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    /// The first node the search settled on that met its goal
+    pub goal: Option<N>,
+    costs: HashMap<N, usize>,
+    predecessors: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new(start: N) -> Self {
+        Self {
+            goal: None,
+            costs: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    fn reach(&mut self, node: N, cost: usize, from: &N) {
+        self.costs.insert(node.clone(), cost);
+        self.predecessors.insert(node, from.clone());
+    }
+
+    pub fn cost(&self, node: &N) -> Option<usize> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    pub fn reached(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    /// The nodes from the start to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        if !self.reached(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from `start`, until a node meets `goal` or there's
+/// nowhere left to go.
+pub fn bfs<N: Clone + Eq + Hash>(
+    start: N,
+    graph: impl Graph<N>,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N> {
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        let cost = search.costs[&node] + 1;
+        for neighbor in graph.neighbors(&node) {
+            if !search.reached(&neighbor) {
+                search.reach(neighbor.clone(), cost, &node);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm from `start`, until the cheapest node left meets
+/// `goal` or there's nowhere left to go.
+pub fn dijkstra<N: Clone + Eq + Hash>(
+    start: N,
+    graph: impl WeightedGraph<N>,
+    goal: impl FnMut(&N) -> bool,
+) -> Search<N> {
+    astar(start, graph, |_| 0, goal)
+}
+
+/// A* from `start`, guided by a `heuristic` that never overestimates the cost
+/// left to a goal.
+pub fn astar<N: Clone + Eq + Hash>(
+    start: N,
+    graph: impl WeightedGraph<N>,
+    heuristic: impl Fn(&N) -> usize,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N> {
+    let mut search = Search::new(start.clone());
+    let mut heap = BinaryHeap::from([Candidate {
+        estimate: heuristic(&start),
+        cost: 0,
+        node: start,
+    }]);
+
+    while let Some(Candidate { cost, node, .. }) = heap.pop() {
+        if cost > search.costs[&node] {
+            // Already settled more cheaply
+            continue;
+        }
+
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (neighbor, step) in graph.edges(&node) {
+            let cost = cost + step;
+            if search.cost(&neighbor).is_some_and(|known| known <= cost) {
+                continue;
+            }
+
+            search.reach(neighbor.clone(), cost, &node);
+            heap.push(Candidate {
+                estimate: cost + heuristic(&neighbor),
+                cost,
+                node: neighbor,
+            });
+        }
+    }
+
+    search
+}
+
+/// The cheapest cost between every pair of the nodes `0..nodes`, or `None`
+/// where there's no path.
+pub fn floyd_warshall(nodes: usize, graph: impl WeightedGraph<usize>) -> Vec<Vec<Option<usize>>> {
+    let mut costs = vec![vec![None; nodes]; nodes];
+
+    for (node, row) in costs.iter_mut().enumerate() {
+        for (neighbor, cost) in graph.edges(&node) {
+            row[neighbor] = Some(row[neighbor].map_or(cost, |known: usize| known.min(cost)));
+        }
+
+        row[node] = Some(0);
+    }
+
+    for k in 0..nodes {
+        for i in 0..nodes {
+            for j in 0..nodes {
+                if let (Some(to_k), Some(from_k)) = (costs[i][k], costs[k][j]) {
+                    let through = to_k + from_k;
+                    if costs[i][j].is_none_or(|direct| direct > through) {
+                        costs[i][j] = Some(through);
+                    }
+                }
+            }
+        }
+    }
+
+    costs
+}
+
+// Ordered so that `BinaryHeap` pops the lowest estimate first
+struct Candidate<N> {
+    estimate: usize,
+    cost: usize,
+    node: N,
+}
+
+impl<N> PartialEq for Candidate<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N> Eq for Candidate<N> {}
+
+impl<N> Ord for Candidate<N> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.estimate.cmp(&other.estimate).reverse()
+    }
+}
+
+impl<N> PartialOrd for Candidate<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1- 1 -1- 2
+    //  \----5----/
+    fn edges(&node: &usize) -> Vec<(usize, usize)> {
+        match node {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(0, 1), (2, 1)],
+            2 => vec![(1, 1), (0, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn weighted_searches_take_the_cheap_way_round() {
+        let search = dijkstra(0, edges, |&node| node == 2);
+        assert_eq!(search.goal_cost(), Some(2));
+        assert_eq!(search.path(&2), Some(vec![0, 1, 2]));
+
+        let search = astar(0, edges, |&node| 2 - node, |&node| node == 2);
+        assert_eq!(search.goal_cost(), Some(2));
+
+        let costs = floyd_warshall(3, edges);
+        assert_eq!(costs[0][2], Some(2));
+        assert_eq!(costs[2][0], Some(2));
+    }
+
+    #[test]
+    fn bfs_counts_steps_and_tracks_predecessors() {
+        let neighbors = |&node: &u32| [node + 1, node * 2].into_iter().filter(|&next| next <= 10);
+        let search = bfs(1, neighbors, |&node| node == 10);
+
+        assert_eq!(search.goal_cost(), Some(4));
+        assert_eq!(search.path(&10), Some(vec![1, 2, 4, 5, 10]));
+        assert_eq!(search.cost(&8), Some(3));
+    }
+
+    #[test]
+    fn unreachable_nodes_have_no_cost() {
+        let costs = floyd_warshall(4, edges);
+        assert_eq!(costs[0][3], None);
+        assert_eq!(costs[3][3], Some(0));
+
+        let search = bfs(
+            3,
+            |&node: &usize| edges(&node).into_iter().map(|(n, _)| n),
+            |_| false,
+        );
+        assert_eq!(search.goal, None);
+        assert_eq!(search.path(&0), None);
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt::Display;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use crate::answers;
+use crate::config::{self, Config, Parameter};
+use crate::generate::Rng;
+use crate::input::InputSource;
+use crate::parse::ParseError;
+
+/// A puzzle answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// Multi-line output that has to be read by eye, like day 10's CRT screen
+    Art(Vec<String>),
+}
+
+impl Answer {
+    pub fn art(art: impl Display) -> Self {
+        Self::Art(art.to_string().lines().map(String::from).collect())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Art(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(number: $t) -> Self {
+                    Self::Number(i64::try_from(number).expect("answer doesn't fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+/// A day's puzzle: parse the input once, then solve each part from that.
+pub trait Solution {
+    /// The puzzle's day, for error messages.
+    const DAY: u8;
+
+    /// The parameters the day reads from the config, so that settings for
+    /// anything else, or of the wrong type, can be turned away up front.
+    const PARAMETERS: &'static [Parameter] = &[];
+
+    type Parsed<'input>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer;
+
+    /// Returns `None` for days without a second puzzle (i.e., day 25).
+    fn part2(parsed: &Self::Parsed<'_>) -> Option<Answer>;
+
+    /// Writes a random input in the puzzle's format. `size` scales how much
+    /// of it there is, such as lines, monkeys or the width of a map.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// One part's answer, and how long solving it took, not counting the parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Parses `input` and solves the requested part, or every part without one.
+pub fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Vec<Solved>, ParseError> {
+    let parsed = S::parse(input).map_err(|err| err.locate(S::DAY, input))?;
+    let mut answers = vec![];
+
+    if part != Some(2) {
+        let start = Instant::now();
+        let answer = S::part1(&parsed);
+        answers.push(Solved {
+            part: 1,
+            answer,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    if part != Some(1) {
+        let start = Instant::now();
+        if let Some(answer) = S::part2(&parsed) {
+            answers.push(Solved {
+                part: 2,
+                answer,
+                elapsed: start.elapsed(),
+            });
+        }
+    }
+
+    Ok(answers)
+}
+
+/// Another way of solving a day than its own [`Solution`], such as one that
+/// trades speed for generality, which `aoc run --impl` picks by name.
+#[derive(Debug, Clone, Copy)]
+pub struct Implementation {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub solve: fn(&str, Option<u8>) -> Result<Vec<Solved>, ParseError>,
+}
+
+impl Implementation {
+    pub const fn new<S: Solution>(name: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            description,
+            solve: solve::<S>,
+        }
+    }
+}
+
+/// Parses `input` without solving it, to find out whether it's well formed.
+pub fn check<S: Solution>(input: &str) -> Result<(), ParseError> {
+    S::parse(input)
+        .map(drop)
+        .map_err(|err| err.locate(S::DAY, input))
+}
+
+/// Lays answers out one part after another, with art starting on its own
+/// line.
+pub fn format_answers(answers: &[Solved]) -> String {
+    let mut output = String::new();
+
+    for Solved { part, answer, .. } in answers {
+        match answer {
+            Answer::Art(_) => output += &format!("part{part} =\n{answer}\n"),
+            _ => output += &format!("part{part} = {answer}\n"),
+        }
+    }
+
+    output
+}
+
+pub fn print_answers(answers: &[Solved]) {
+    print!("{}", format_answers(answers));
+}
+
+/// Solves both parts and prints them, for a standalone day binary.
+///
+/// The day's parameters come from `aoc.toml` and the fixture's entry in
+/// `answers.txt`, as they do for `aoc run`.
+pub fn run<S: Solution>(input: &str) -> ExitCode {
+    if let Err(err) = configure::<S>(&InputSource::from_args()) {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+
+    match solve::<S>(input, None) {
+        Ok(answers) => {
+            print_answers(&answers);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn configure<S: Solution>(source: &InputSource) -> Result<(), String> {
+    let mut config = Config::load(None)?.for_day(S::DAY);
+    answers::configure(&mut config, S::DAY, source)?;
+    config.validate(|day| if day == S::DAY { S::PARAMETERS } else { &[] })?;
+    config::install(config);
+    Ok(())
+}
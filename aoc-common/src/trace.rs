@@ -0,0 +1,119 @@
+use std::fmt::{Arguments, Display};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Emits a trace event if tracing was turned on for its level and name.
+///
+/// Takes the [`Level`] variant, the event's name and a format string with its
+/// arguments, which are only formatted when the event is printed:
+///
+/// ```
+/// # let (rock, top) = (1, 4);
+/// aoc_common::trace!(Round, "rock", "{rock} came to rest, top at {top}");
+/// ```
+#[macro_export]
+macro_rules! trace {
+    ($level:ident, $event:literal, $($arg:tt)+) => {
+        if $crate::trace::enabled($crate::trace::Level::$level, $event) {
+            $crate::trace::emit($crate::trace::Level::$level, $event, format_args!($($arg)+));
+        }
+    };
+}
+
+/// How often an event happens while solving, from least to most often.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Once or a few times per part, such as the final state of a simulation
+    Part,
+    /// Once per round, rock, trip or other unit of a simulation
+    Round,
+    /// Every step inside a round
+    Step,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Level::Part => "part",
+            Level::Round => "round",
+            Level::Step => "step",
+        })
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "part" => Ok(Level::Part),
+            "round" => Ok(Level::Round),
+            "step" => Ok(Level::Step),
+            _ => Err(format!("expected `part`, `round` or `step`, got `{s}`")),
+        }
+    }
+}
+
+/// Which events get printed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    /// The most frequent level printed
+    pub level: Level,
+    /// The names of the events printed, or every event if empty
+    pub events: Vec<String>,
+}
+
+impl Filter {
+    fn allows(&self, level: Level, event: &str) -> bool {
+        level <= self.level && (self.events.is_empty() || self.events.iter().any(|e| e == event))
+    }
+}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+
+/// Turns tracing on for the rest of the process. Only the first call has any
+/// effect.
+pub fn enable(filter: Filter) {
+    let _ = FILTER.set(filter);
+}
+
+pub fn enabled(level: Level, event: &str) -> bool {
+    FILTER
+        .get()
+        .is_some_and(|filter| filter.allows(level, event))
+}
+
+/// Prints an event to stderr, one `level event: message` line, with any
+/// further lines of the message (such as a drawn grid) after it.
+pub fn emit(level: Level, event: &str, message: Arguments) {
+    let message = message.to_string();
+    let separator = if message.starts_with('\n') { "" } else { " " };
+    eprintln!(
+        "{level:<5} {event}:{separator}{}",
+        message.trim_end_matches('\n')
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_by_level_and_name() {
+        let filter = Filter {
+            level: Level::Round,
+            events: vec![],
+        };
+        assert!(filter.allows(Level::Part, "tower"));
+        assert!(filter.allows(Level::Round, "rock"));
+        assert!(!filter.allows(Level::Step, "jet"));
+
+        let filter = Filter {
+            level: Level::Step,
+            events: vec!["jet".to_string()],
+        };
+        assert!(filter.allows(Level::Step, "jet"));
+        assert!(!filter.allows(Level::Round, "rock"));
+        assert_eq!("round".parse(), Ok(Level::Round));
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt::Display;
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc_common::input::{day_directory, InputSource};
+use serde::{Deserialize, Serialize};
+
+use crate::days::Day;
+
+// Phases slower than this, compared to the baseline, are called out
+const REGRESSION_PERCENT: f64 = 10.0;
+
+/// A benchmark run, as saved by `--save` and read back by `--baseline`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub timings: Vec<Timing>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        })
+    }
+}
+
+/// One phase of one day, over every iteration.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub phase: Phase,
+    pub median_ns: u64,
+    pub min_ns: u64,
+}
+
+impl Timing {
+    fn new(day: u8, phase: Phase, samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        Some(Self {
+            day,
+            phase,
+            median_ns: nanos(*samples.get(samples.len() / 2)?),
+            min_ns: nanos(*samples.first()?),
+        })
+    }
+
+    fn change_from(&self, baseline: &Report) -> Option<f64> {
+        let before = baseline
+            .timings
+            .iter()
+            .find(|timing| timing.day == self.day && timing.phase == self.phase)?;
+
+        let before = before.median_ns as f64;
+        Some((self.median_ns as f64 - before) / before * 100.0)
+    }
+}
+
+impl Report {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Ok(serde_json::from_str(&read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        write(path, serde_json::to_string_pretty(self)? + "\n")
+    }
+}
+
+/// Benchmarks each day on its real input, printing the timings as they come
+/// in and how they compare to `baseline`.
+pub fn run(days: &[Day], iterations: usize, baseline: Option<&Report>) -> (Report, ExitCode) {
+    let mut report = Report {
+        iterations,
+        timings: vec![],
+    };
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut regressions = 0;
+
+    println!("day  phase  {:>9}  {:>9}  {:>8}", "median", "min", "change");
+
+    for day in days {
+        let samples = InputSource::Real
+            .read(day_directory(day.number))
+            .map_err(|err| format!("couldn't read input.txt for day {:02}: {err}", day.number))
+            .and_then(|input| {
+                (day.bench)(&input, iterations)
+                    .map_err(|err| format!("couldn't parse input.txt: {err}"))
+            });
+
+        let samples = match samples {
+            Ok(samples) => samples,
+            Err(err) => {
+                eprintln!("{err}");
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let timings = [
+            Timing::new(day.number, Phase::Parse, &samples.parse),
+            Timing::new(day.number, Phase::Part1, &samples.part1),
+            Timing::new(day.number, Phase::Part2, &samples.part2),
+        ];
+
+        for timing in timings.into_iter().flatten() {
+            let change = baseline.and_then(|baseline| timing.change_from(baseline));
+            if change.is_some_and(|change| change > REGRESSION_PERCENT) {
+                regressions += 1;
+            }
+
+            let row = format!(
+                "{:02}   {:<5}  {:>9}  {:>9}  {:>8}",
+                timing.day,
+                timing.phase,
+                format_duration(timing.median_ns),
+                format_duration(timing.min_ns),
+                change
+                    .map(|change| format!("{change:+.1}%"))
+                    .unwrap_or_default(),
+            );
+            println!("{}", row.trim_end());
+            report.timings.push(timing);
+        }
+    }
+
+    if regressions > 0 {
+        println!("{regressions} phase(s) more than {REGRESSION_PERCENT}% slower than the baseline");
+    }
+
+    (report, exit_code)
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+pub(crate) fn format_duration(nanos: u64) -> String {
+    let nanos = nanos as f64;
+
+    if nanos < 1e3 {
+        format!("{nanos:.0}ns")
+    } else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
@@ -0,0 +1,114 @@
+use std::process::ExitCode;
+
+use aoc_common::input::{day_directory, InputSource};
+use aoc_common::solution::{Answer, Implementation, Solved};
+
+use crate::bench::format_duration;
+use crate::days::Day;
+use crate::summary::cell;
+use crate::{Format, Record};
+
+/// Solves the day with each implementation in turn, then prints each part's
+/// answers and timings side by side, and whether they agree with the first
+/// implementation's.
+pub fn run(
+    day: &Day,
+    implementations: &[Implementation],
+    part: Option<u8>,
+    source: &InputSource,
+    format: Format,
+) -> ExitCode {
+    let input = match source.read(day_directory(day.number)) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("couldn't read {source} for day {:02}: {err}", day.number);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut solved = vec![];
+    for implementation in implementations {
+        match (implementation.solve)(&input, part) {
+            Ok(answers) => solved.push((implementation.name, answers)),
+            Err(err) => {
+                eprintln!("couldn't parse {source}: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if solved.iter().any(|(_, answers)| answers.is_empty()) {
+        eprintln!("day {:02} has no part 2", day.number);
+        return ExitCode::FAILURE;
+    }
+
+    let mut differ = false;
+    let rows: Vec<(&str, &Solved, bool)> = (0..solved[0].1.len())
+        .flat_map(|i| {
+            let first = &solved[0].1[i].answer;
+            solved.iter().map(move |(name, answers)| {
+                let solved = &answers[i];
+                (*name, solved, solved.answer == *first)
+            })
+        })
+        .inspect(|(_, _, same)| differ |= !same)
+        .collect();
+
+    match format {
+        Format::Text => print(&rows),
+        Format::Json => {
+            for &(name, solved, _) in &rows {
+                let record = Record {
+                    implementation: Some(name),
+                    ..Record::new(day.number, solved)
+                };
+                println!("{}", serde_json::to_string(&record).unwrap());
+            }
+        }
+    }
+
+    if differ {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// A row per part and implementation, with art on the lines after its row
+fn print(rows: &[(&str, &Solved, bool)]) {
+    let name_width = rows
+        .iter()
+        .map(|(name, _, _)| name.len())
+        .fold("impl".len(), usize::max);
+    let width = rows
+        .iter()
+        .map(|(_, solved, _)| cell(&solved.answer).len())
+        .fold("answer".len(), usize::max);
+
+    println!(
+        "part  {:<name_width$}  {:<width$}  {:>9}",
+        "impl", "answer", "time"
+    );
+    for (i, &(name, solved, same)) in rows.iter().enumerate() {
+        // The first implementation is what the rest are compared with
+        let verdict = match (i > 0 && rows[i - 1].1.part == solved.part, same) {
+            (false, _) => "",
+            (true, true) => "same",
+            (true, false) => "differs",
+        };
+
+        let row = format!(
+            "{:<4}  {name:<name_width$}  {:<width$}  {:>9}  {verdict}",
+            solved.part,
+            cell(&solved.answer),
+            format_duration(u64::try_from(solved.elapsed.as_nanos()).unwrap_or(u64::MAX)),
+        );
+        println!("{}", row.trim_end());
+
+        if let Answer::Art(lines) = &solved.answer {
+            for line in lines {
+                println!("{:6}{line}", "");
+            }
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use aoc_common::bench::{bench, Samples};
+use aoc_common::config::Parameter;
+use aoc_common::generate::generate;
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{check, solve, Implementation, Solution, Solved};
+
+/// Parses the input and solves the requested part, or both parts.
+pub type Solver = fn(&str, Option<u8>) -> Result<Vec<Solved>, ParseError>;
+
+/// Parses the input without solving it.
+pub type Checker = fn(&str) -> Result<(), ParseError>;
+
+/// Times the parse and both parts over the given number of iterations.
+pub type Bencher = fn(&str, usize) -> Result<Samples, ParseError>;
+
+/// Makes a random input from a seed and a size.
+pub type Generator = fn(u64, usize) -> String;
+
+/// A day's solver, as exposed by its crate.
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+    pub check: Checker,
+    pub bench: Bencher,
+    pub generate: Generator,
+    /// The parameters the day reads, for checking the config against
+    pub parameters: &'static [Parameter],
+    /// Other ways of solving the day than `solve`, for `aoc run --impl`
+    pub alternatives: &'static [Implementation],
+}
+
+impl Day {
+    /// Every way of solving the day, starting with `solve` as `default`.
+    pub fn implementations(&self) -> Vec<Implementation> {
+        let default = Implementation {
+            name: "default",
+            description: "the day's own solver",
+            solve: self.solve,
+        };
+
+        std::iter::once(default)
+            .chain(self.alternatives.iter().copied())
+            .collect()
+    }
+}
+
+pub const DAYS: [Day; 25] = [
+    Day {
+        number: 1,
+        solve: solve::<day_01::Day01>,
+        check: check::<day_01::Day01>,
+        bench: bench::<day_01::Day01>,
+        generate: generate::<day_01::Day01>,
+        parameters: day_01::Day01::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 2,
+        solve: solve::<day_02::Day02>,
+        check: check::<day_02::Day02>,
+        bench: bench::<day_02::Day02>,
+        generate: generate::<day_02::Day02>,
+        parameters: day_02::Day02::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 3,
+        solve: solve::<day_03::Day03>,
+        check: check::<day_03::Day03>,
+        bench: bench::<day_03::Day03>,
+        generate: generate::<day_03::Day03>,
+        parameters: day_03::Day03::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 4,
+        solve: solve::<day_04::Day04>,
+        check: check::<day_04::Day04>,
+        bench: bench::<day_04::Day04>,
+        generate: generate::<day_04::Day04>,
+        parameters: day_04::Day04::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 5,
+        solve: solve::<day_05::Day05>,
+        check: check::<day_05::Day05>,
+        bench: bench::<day_05::Day05>,
+        generate: generate::<day_05::Day05>,
+        parameters: day_05::Day05::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 6,
+        solve: solve::<day_06::Day06>,
+        check: check::<day_06::Day06>,
+        bench: bench::<day_06::Day06>,
+        generate: generate::<day_06::Day06>,
+        parameters: day_06::Day06::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 7,
+        solve: solve::<day_07::Day07>,
+        check: check::<day_07::Day07>,
+        bench: bench::<day_07::Day07>,
+        generate: generate::<day_07::Day07>,
+        parameters: day_07::Day07::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 8,
+        solve: solve::<day_08::Day08>,
+        check: check::<day_08::Day08>,
+        bench: bench::<day_08::Day08>,
+        generate: generate::<day_08::Day08>,
+        parameters: day_08::Day08::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 9,
+        solve: solve::<day_09::Day09>,
+        check: check::<day_09::Day09>,
+        bench: bench::<day_09::Day09>,
+        generate: generate::<day_09::Day09>,
+        parameters: day_09::Day09::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 10,
+        solve: solve::<day_10::Day10>,
+        check: check::<day_10::Day10>,
+        bench: bench::<day_10::Day10>,
+        generate: generate::<day_10::Day10>,
+        parameters: day_10::Day10::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 11,
+        solve: solve::<day_11::Day11>,
+        check: check::<day_11::Day11>,
+        bench: bench::<day_11::Day11>,
+        generate: generate::<day_11::Day11>,
+        parameters: day_11::Day11::PARAMETERS,
+        alternatives: &day_11::ALTERNATIVES,
+    },
+    Day {
+        number: 12,
+        solve: solve::<day_12::Day12>,
+        check: check::<day_12::Day12>,
+        bench: bench::<day_12::Day12>,
+        generate: generate::<day_12::Day12>,
+        parameters: day_12::Day12::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 13,
+        solve: solve::<day_13::Day13>,
+        check: check::<day_13::Day13>,
+        bench: bench::<day_13::Day13>,
+        generate: generate::<day_13::Day13>,
+        parameters: day_13::Day13::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 14,
+        solve: solve::<day_14::Day14>,
+        check: check::<day_14::Day14>,
+        bench: bench::<day_14::Day14>,
+        generate: generate::<day_14::Day14>,
+        parameters: day_14::Day14::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 15,
+        solve: solve::<day_15::Day15>,
+        check: check::<day_15::Day15>,
+        bench: bench::<day_15::Day15>,
+        generate: generate::<day_15::Day15>,
+        parameters: day_15::Day15::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 16,
+        solve: solve::<day_16::Day16>,
+        check: check::<day_16::Day16>,
+        bench: bench::<day_16::Day16>,
+        generate: generate::<day_16::Day16>,
+        parameters: day_16::Day16::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 17,
+        solve: solve::<day_17::Day17>,
+        check: check::<day_17::Day17>,
+        bench: bench::<day_17::Day17>,
+        generate: generate::<day_17::Day17>,
+        parameters: day_17::Day17::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 18,
+        solve: solve::<day_18::Day18>,
+        check: check::<day_18::Day18>,
+        bench: bench::<day_18::Day18>,
+        generate: generate::<day_18::Day18>,
+        parameters: day_18::Day18::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 19,
+        solve: solve::<day_19::Day19>,
+        check: check::<day_19::Day19>,
+        bench: bench::<day_19::Day19>,
+        generate: generate::<day_19::Day19>,
+        parameters: day_19::Day19::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 20,
+        solve: solve::<day_20::Day20>,
+        check: check::<day_20::Day20>,
+        bench: bench::<day_20::Day20>,
+        generate: generate::<day_20::Day20>,
+        parameters: day_20::Day20::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 21,
+        solve: solve::<day_21::Day21>,
+        check: check::<day_21::Day21>,
+        bench: bench::<day_21::Day21>,
+        generate: generate::<day_21::Day21>,
+        parameters: day_21::Day21::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 22,
+        solve: solve::<day_22::Day22>,
+        check: check::<day_22::Day22>,
+        bench: bench::<day_22::Day22>,
+        generate: generate::<day_22::Day22>,
+        parameters: day_22::Day22::PARAMETERS,
+        alternatives: &day_22::ALTERNATIVES,
+    },
+    Day {
+        number: 23,
+        solve: solve::<day_23::Day23>,
+        check: check::<day_23::Day23>,
+        bench: bench::<day_23::Day23>,
+        generate: generate::<day_23::Day23>,
+        parameters: day_23::Day23::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 24,
+        solve: solve::<day_24::Day24>,
+        check: check::<day_24::Day24>,
+        bench: bench::<day_24::Day24>,
+        generate: generate::<day_24::Day24>,
+        parameters: day_24::Day24::PARAMETERS,
+        alternatives: &[],
+    },
+    Day {
+        number: 25,
+        solve: solve::<day_25::Day25>,
+        check: check::<day_25::Day25>,
+        bench: bench::<day_25::Day25>,
+        generate: generate::<day_25::Day25>,
+        parameters: day_25::Day25::PARAMETERS,
+        alternatives: &day_25::ALTERNATIVES,
+    },
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::config::{default_path, Config};
+
+    #[test]
+    fn aoc_toml_only_sets_declared_parameters() {
+        let config = Config::load(Some(&default_path())).unwrap();
+        assert_eq!(config.validate(|day| get(day).unwrap().parameters), Ok(()));
+    }
+}
@@ -0,0 +1,601 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use aoc_common::answers;
+use aoc_common::config::{self, Config};
+use aoc_common::image::{self, Export};
+use aoc_common::input::{day_directory, InputSource};
+use aoc_common::pool;
+use aoc_common::solution::{print_answers, Answer, Implementation, Solved};
+use aoc_common::trace::{self, Filter, Level};
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use serde_json::json;
+
+use bench::Report;
+use days::{Day, Solver};
+use serve::Server;
+
+mod bench;
+mod compare;
+mod days;
+mod serve;
+mod summary;
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    /// Read the days' parameters from this file instead of the workspace's
+    /// aoc.toml
+    #[arg(long, value_name = "FILE", global = true)]
+    config: Option<PathBuf>,
+
+    /// Set a day's parameter over the config file, as `day-15.row=10`, or as
+    /// `row=10` for every day being run that doesn't set `row` itself
+    #[arg(long = "set", value_name = "NAME=VALUE", global = true)]
+    settings: Vec<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day, or every day
+    Run {
+        /// Day number (1-25) or `all`
+        day: DaySelection,
+
+        /// Only solve this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the puzzle input from this file (`-` for stdin) instead of the
+        /// day's input.txt
+        #[arg(short, long, conflicts_with = "test")]
+        input: Option<PathBuf>,
+
+        /// Use the day's test_input.txt, or test_input<N>.txt
+        #[arg(short, long, value_name = "N")]
+        test: Option<Option<u8>>,
+
+        /// How to print the answers
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Print the days' trace events to stderr, up to how often they
+        /// happen: `part`, `round` or `step`
+        #[arg(long, value_name = "LEVEL")]
+        trace: Option<Level>,
+
+        /// Only print trace events with this name, such as `rock`
+        #[arg(long, value_name = "NAME", requires = "trace")]
+        trace_event: Vec<String>,
+
+        /// Draw the simulations step by step on the terminal, at this many
+        /// frames per second
+        #[arg(long, value_name = "FPS", num_args = 0..=1, default_missing_value = "10", value_parser = clap::value_parser!(u32).range(1..))]
+        animate: Option<u32>,
+
+        /// Write pictures of the days' grids and simulations into this
+        /// directory
+        #[arg(long, value_name = "DIR")]
+        images: Option<PathBuf>,
+
+        /// The pictures' file format: `png`, `ppm` or `svg`
+        #[arg(
+            long,
+            value_name = "FORMAT",
+            default_value = "png",
+            requires = "images"
+        )]
+        image_format: image::Format,
+
+        /// How many pixels wide and tall each tile is drawn
+        #[arg(long, value_name = "PIXELS", default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..), requires = "images")]
+        image_scale: u32,
+
+        /// Solve on this many threads at once, and with `all`, solve every
+        /// day at the same time and print a table of how they did
+        #[arg(short, long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..), conflicts_with = "animate")]
+        jobs: Option<u32>,
+
+        /// Solve with this one of the day's implementations, as listed by
+        /// `aoc impls`; given more than once, or as `all`, compares them side
+        /// by side
+        #[arg(long = "impl", value_name = "NAME")]
+        implementations: Vec<String>,
+    },
+    /// List the ways each day can be solved, for `aoc run --impl`
+    Impls {
+        /// Day number (1-25) or `all`
+        #[arg(default_value = "all")]
+        day: DaySelection,
+    },
+    /// Parse a puzzle input without solving it, reporting the first thing
+    /// wrong with it
+    Check {
+        /// Day number (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The puzzle input (`-` for stdin)
+        input: PathBuf,
+    },
+    /// Time the parse and both parts of one day, or every day, on the real
+    /// input
+    Bench {
+        /// Day number (1-25) or `all`
+        day: DaySelection,
+
+        /// How many times to run each day
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// Write the timings to this JSON file
+        #[arg(long, value_name = "FILE")]
+        save: Option<PathBuf>,
+
+        /// Compare against timings saved by an earlier `--save`
+        #[arg(long, value_name = "FILE")]
+        baseline: Option<PathBuf>,
+    },
+    /// Answer puzzle inputs sent over HTTP on localhost: `POST /day/{n}` or
+    /// `POST /day/{n}/part/{p}` with the input as the body
+    Serve {
+        /// The port to listen on, or 0 for any free one
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
+    /// Print a random puzzle input for a day
+    Gen {
+        /// Day number (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The same seed always makes the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How big an input to make, such as how many lines or monkeys
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        size: u32,
+    },
+}
+
+impl Command {
+    // Adds the parameters `answers.txt` gives the inputs being run, under
+    // whatever `--set` adds after
+    fn configure(&self, config: &mut Config) -> Result<(), String> {
+        let Command::Run {
+            day, input, test, ..
+        } = self
+        else {
+            return Ok(());
+        };
+
+        let source = source(input.clone(), *test);
+        let days = match day {
+            DaySelection::Day(number) => std::slice::from_ref(days::get(*number).unwrap()),
+            DaySelection::All => &days::DAYS,
+        };
+        for day in days {
+            answers::configure(config, day.number, &source)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn source(input: Option<PathBuf>, test: Option<Option<u8>>) -> InputSource {
+    match (input, test) {
+        (Some(path), _) => InputSource::from_path(path),
+        (None, Some(number)) => InputSource::Test(number),
+        (None, None) => InputSource::Real,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// `part1 = <answer>` lines
+    Text,
+    /// One `{"day", "part", "answer", "elapsed"}` object per line, with
+    /// `elapsed` in seconds and art as an array of its rows
+    Json,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            _ => match s.parse() {
+                Ok(number @ 1..=25) => Ok(Self::Day(number)),
+                _ => Err(format!("expected a day from 1 to 25 or `all`, got `{s}`")),
+            },
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let mut config = match Config::load(cli.config.as_deref()) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(err) = cli.command.configure(&mut config) {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+    for setting in &cli.settings {
+        if let Err(err) = config.set(setting) {
+            Cli::command()
+                .error(
+                    ErrorKind::ValueValidation,
+                    format!("--set {setting}: {err}"),
+                )
+                .exit();
+        }
+    }
+    if let Err(err) = config.validate(|day| days::get(day).unwrap().parameters) {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+    config::install(config);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            test,
+            format,
+            trace,
+            trace_event,
+            animate,
+            images,
+            image_format,
+            image_scale,
+            jobs,
+            implementations,
+        } => {
+            if let Some(level) = trace {
+                trace::enable(Filter {
+                    level,
+                    events: trace_event,
+                });
+            }
+            if let Some(fps) = animate {
+                aoc_common::animate::enable(fps);
+            }
+            if let Some(directory) = images {
+                image::enable(Export {
+                    directory,
+                    format: image_format,
+                    scale: image_scale as usize,
+                });
+            }
+
+            if let Some(jobs) = jobs {
+                pool::set_threads(jobs as usize);
+            }
+
+            let source = source(input, test);
+
+            match day {
+                DaySelection::Day(number) => {
+                    let day = days::get(number).unwrap();
+                    let implementations = match select(day, &implementations) {
+                        Ok(implementations) => implementations,
+                        Err(err) => {
+                            eprintln!("{err}");
+                            return ExitCode::FAILURE;
+                        }
+                    };
+
+                    match implementations[..] {
+                        [] => run(day.number, day.solve, part, &source, format, false),
+                        [implementation] => run(
+                            day.number,
+                            implementation.solve,
+                            part,
+                            &source,
+                            format,
+                            false,
+                        ),
+                        _ => compare::run(day, &implementations, part, &source, format),
+                    }
+                }
+                DaySelection::All => {
+                    if !implementations.is_empty() {
+                        Cli::command()
+                            .error(
+                                ErrorKind::ArgumentConflict,
+                                "--impl can't be used with `all`",
+                            )
+                            .exit();
+                    }
+                    if matches!(source, InputSource::Path(_) | InputSource::Stdin) {
+                        Cli::command()
+                            .error(
+                                ErrorKind::ArgumentConflict,
+                                "--input can't be used with `all`",
+                            )
+                            .exit();
+                    }
+
+                    if jobs.is_some() {
+                        return run_all(part, &source, format);
+                    }
+
+                    let mut exit_code = ExitCode::SUCCESS;
+                    for day in &days::DAYS {
+                        if format == Format::Text {
+                            println!("day {:02}", day.number);
+                        }
+                        if run(day.number, day.solve, part, &source, format, true)
+                            == ExitCode::FAILURE
+                        {
+                            exit_code = ExitCode::FAILURE;
+                        }
+                    }
+
+                    exit_code
+                }
+            }
+        }
+        Command::Impls { day } => {
+            let days = match day {
+                DaySelection::Day(number) => std::slice::from_ref(days::get(number).unwrap()),
+                DaySelection::All => &days::DAYS,
+            };
+
+            for day in days {
+                println!("day {:02}", day.number);
+                let implementations = day.implementations();
+                let width = implementations.iter().map(|i| i.name.len()).max().unwrap();
+                for Implementation {
+                    name, description, ..
+                } in implementations
+                {
+                    println!("  {name:<width$}  {description}");
+                }
+            }
+
+            ExitCode::SUCCESS
+        }
+        Command::Check { day, input } => {
+            let day = days::get(day).unwrap();
+            let source = InputSource::from_path(input);
+            let input = match source.read(day_directory(day.number)) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("couldn't read {source}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            match (day.check)(&input) {
+                Ok(()) => {
+                    println!("{source}: ok");
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("{source}: {err}");
+                    if let Some(underline) = err.underline(&input) {
+                        eprintln!("{underline}");
+                    }
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            save,
+            baseline,
+        } => {
+            let baseline = match baseline.map(|path| Report::load(&path).map_err(|err| (path, err)))
+            {
+                None => None,
+                Some(Ok(baseline)) => Some(baseline),
+                Some(Err((path, err))) => {
+                    eprintln!("couldn't read {}: {err}", path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let days = match day {
+                DaySelection::Day(number) => std::slice::from_ref(days::get(number).unwrap()),
+                DaySelection::All => &days::DAYS,
+            };
+
+            let (report, exit_code) = bench::run(days, iterations as usize, baseline.as_ref());
+            if let Some(path) = save {
+                if let Err(err) = report.save(&path) {
+                    eprintln!("couldn't write {}: {err}", path.display());
+                    return ExitCode::FAILURE;
+                }
+            }
+
+            exit_code
+        }
+        Command::Serve { port } => {
+            let server = match Server::bind(port) {
+                Ok(server) => server,
+                Err(err) => {
+                    eprintln!("couldn't listen on port {port}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            if let Ok(address) = server.local_addr() {
+                println!("listening on http://{address}");
+            }
+            server.run();
+            ExitCode::SUCCESS
+        }
+        Command::Gen { day, seed, size } => {
+            let day = days::get(day).unwrap();
+            print!("{}", (day.generate)(seed, size as usize));
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+// The implementations `--impl` names, in the order they're named, or none
+// without any names
+fn select(day: &Day, names: &[String]) -> Result<Vec<Implementation>, String> {
+    let implementations = day.implementations();
+    if names.iter().any(|name| name == "all") {
+        return Ok(implementations);
+    }
+
+    names
+        .iter()
+        .map(|name| {
+            implementations
+                .iter()
+                .find(|implementation| implementation.name == name)
+                .copied()
+                .ok_or_else(|| {
+                    let names: Vec<&str> = implementations.iter().map(|i| i.name).collect();
+                    format!(
+                        "day {:02} has no implementation named `{name}`, only {}",
+                        day.number,
+                        names.join(", ")
+                    )
+                })
+        })
+        .collect()
+}
+
+// With `all`, a day without the part asked for isn't a failure, as the other
+// days have it, and a day that panics is reported so the rest still run
+fn run(
+    day: u8,
+    solve: Solver,
+    part: Option<u8>,
+    source: &InputSource,
+    format: Format,
+    all: bool,
+) -> ExitCode {
+    let input = match source.read(day_directory(day)) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("couldn't read {source} for day {day:02}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solved = if all {
+        match catch_unwind(AssertUnwindSafe(|| solve(&input, part))) {
+            Ok(solved) => solved,
+            Err(panic) => {
+                eprintln!("day {day:02} {}", summary::panic_message(&*panic));
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        solve(&input, part)
+    };
+    let answers = match solved {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("couldn't parse {source}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if answers.is_empty() && all {
+        if format == Format::Text {
+            println!("part2 = n/a");
+        }
+        return ExitCode::SUCCESS;
+    }
+    if answers.is_empty() {
+        eprintln!("day {day:02} has no part 2");
+        return ExitCode::FAILURE;
+    }
+
+    match format {
+        Format::Text => print_answers(&answers),
+        Format::Json => {
+            for solved in &answers {
+                println!("{}", json_record(day, solved));
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+// Every day at once on the thread pool, as a table or as every day's records
+fn run_all(part: Option<u8>, source: &InputSource, format: Format) -> ExitCode {
+    let outcomes = summary::solve_all(&days::DAYS, part, source);
+    match format {
+        Format::Text => summary::print(&outcomes),
+        Format::Json => {
+            for outcome in &outcomes {
+                match &outcome.answers {
+                    Ok(answers) => {
+                        for solved in answers {
+                            println!("{}", json_record(outcome.day, solved));
+                        }
+                    }
+                    Err(err) => eprintln!("day {:02}: {err}", outcome.day),
+                }
+            }
+        }
+    }
+
+    summary::exit_code(&outcomes)
+}
+
+/// A solved part, as printed by `--format json`.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    answer: serde_json::Value,
+    elapsed: f64,
+    /// Which of the day's implementations solved it, when comparing them
+    #[serde(rename = "impl", skip_serializing_if = "Option::is_none")]
+    implementation: Option<&'static str>,
+}
+
+impl Record {
+    fn new(day: u8, solved: &Solved) -> Self {
+        let answer = match &solved.answer {
+            Answer::Number(number) => json!(number),
+            Answer::Text(text) => json!(text),
+            Answer::Art(rows) => json!(rows),
+        };
+
+        Self {
+            day,
+            part: solved.part,
+            answer,
+            elapsed: solved.elapsed.as_secs_f64(),
+            implementation: None,
+        }
+    }
+}
+
+fn json_record(day: u8, solved: &Solved) -> String {
+    serde_json::to_string(&Record::new(day, solved)).unwrap()
+}
@@ -0,0 +1,267 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::thread;
+use std::time::Instant;
+
+use serde_json::{json, Value};
+
+use crate::days;
+use crate::summary::panic_message;
+use crate::Record;
+
+// Bigger than any day's real input by far
+const MAX_BODY: usize = 16 << 20;
+
+/// A response to send back, always with a JSON body.
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Response {
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+/// Answers the request for `path` with the puzzle input in `body`:
+///
+/// - `POST /day/{n}` solves both parts
+/// - `POST /day/{n}/part/{p}` solves only part `p`
+///
+/// A solved input gets its answers as `aoc run --format json` prints them,
+/// along with how long the whole request took to solve, and an input that
+/// doesn't parse gets its parse error.
+pub fn respond(method: &str, path: &str, body: &[u8]) -> Response {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (day, part) = match segments[..] {
+        ["day", day] => (day, None),
+        ["day", day, "part", part] => (day, Some(part)),
+        _ => return Response::error(404, format!("no such endpoint: {path}")),
+    };
+
+    let Some(day) = day.parse().ok().and_then(days::get) else {
+        return Response::error(404, format!("expected a day from 1 to 25, got `{day}`"));
+    };
+    let part = match part {
+        None => None,
+        Some(part) => match part.parse() {
+            Ok(part @ 1..=2) => Some(part),
+            _ => return Response::error(404, format!("expected part 1 or 2, got `{part}`")),
+        },
+    };
+
+    if method != "POST" {
+        return Response::error(405, format!("expected POST, got {method}"));
+    }
+    let Ok(input) = std::str::from_utf8(body) else {
+        return Response::error(400, "the puzzle input isn't UTF-8");
+    };
+
+    let start = Instant::now();
+    let answers = match catch_unwind(AssertUnwindSafe(|| (day.solve)(input, part))) {
+        Ok(Ok(answers)) => answers,
+        Ok(Err(err)) => return Response::error(422, err.to_string()),
+        Err(panic) => return Response::error(500, panic_message(&*panic)),
+    };
+    let elapsed = start.elapsed();
+
+    if answers.is_empty() {
+        return Response::error(404, format!("day {:02} has no part 2", day.number));
+    }
+
+    let answers: Vec<Record> = answers
+        .iter()
+        .map(|solved| Record::new(day.number, solved))
+        .collect();
+    Response {
+        status: 200,
+        body: json!({
+            "day": day.number,
+            "answers": answers,
+            "elapsed": elapsed.as_secs_f64(),
+        }),
+    }
+}
+
+/// An HTTP server for [`respond`], on the loopback interface only.
+pub struct Server {
+    listener: TcpListener,
+}
+
+impl Server {
+    /// Listens on `port`, or on any free port if it's 0.
+    pub fn bind(port: u16) -> io::Result<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        Ok(Self { listener })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Answers requests until the process ends, each connection on a thread
+    /// of its own so a slow day doesn't hold up the rest.
+    pub fn run(&self) {
+        for stream in self.listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    thread::spawn(move || {
+                        if let Err(err) = handle(stream) {
+                            eprintln!("couldn't answer a request: {err}");
+                        }
+                    });
+                }
+                Err(err) => eprintln!("couldn't accept a connection: {err}"),
+            }
+        }
+    }
+}
+
+// One request per connection, which is closed after the response
+fn handle(stream: TcpStream) -> io::Result<()> {
+    let mut reader = BufReader::new(&stream);
+    let response = match read_request(&mut reader)? {
+        Ok(request) => respond(&request.method, &request.path, &request.body),
+        Err(response) => response,
+    };
+
+    let body = response.body.to_string();
+    let mut stream = &stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        response.status,
+        response.reason(),
+        body.len(),
+    )?;
+    stream.flush()
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+// Or the response for a request that can't be read
+fn read_request(reader: &mut impl BufRead) -> io::Result<Result<Request, Response>> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut words = line.split_whitespace();
+    let (Some(method), Some(path)) = (words.next(), words.next()) else {
+        return Ok(Err(Response::error(400, "expected a request line")));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut length = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                let Ok(value) = value.trim().parse() else {
+                    return Ok(Err(Response::error(
+                        400,
+                        "expected a number for Content-Length",
+                    )));
+                };
+                length = value;
+            }
+        }
+    }
+
+    if length > MAX_BODY {
+        return Ok(Err(Response::error(
+            413,
+            format!("inputs are limited to {MAX_BODY} bytes"),
+        )));
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    Ok(Ok(Request { method, path, body }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    const INPUT: &str = "1000\n2000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    // A client for the server, as small as the server
+    fn request(address: SocketAddr, method: &str, path: &str, body: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    #[test]
+    fn solves_parts_over_http() {
+        let server = Server::bind(0).unwrap();
+        let address = server.local_addr().unwrap();
+        thread::spawn(move || server.run());
+
+        let (status, body) = request(address, "POST", "/day/1/part/2", INPUT);
+        assert_eq!(status, 200);
+        assert_eq!(body["day"], 1);
+        assert_eq!(body["answers"][0]["part"], 2);
+        assert_eq!(body["answers"][0]["answer"], 45000);
+        assert!(body["elapsed"].is_f64());
+
+        let (status, body) = request(address, "POST", "/day/1", INPUT);
+        assert_eq!(status, 200);
+        assert_eq!(body["answers"].as_array().unwrap().len(), 2);
+        assert_eq!(body["answers"][0]["answer"], 24000);
+    }
+
+    #[test]
+    fn reports_what_went_wrong() {
+        let error = |response: Response| (response.status, response.body["error"].clone());
+
+        assert_eq!(
+            error(respond("POST", "/day/1", b"1000\nlots\n")),
+            (
+                422,
+                json!("day 01, line 2, column 1: expected a number, found \"lots\"")
+            )
+        );
+        assert_eq!(error(respond("POST", "/day/26", b"")).0, 404);
+        assert_eq!(error(respond("POST", "/day/1/part/3", b"")).0, 404);
+        assert_eq!(error(respond("GET", "/day/1", b"")).0, 405);
+        assert_eq!(
+            error(respond("POST", "/day/25/part/2", b"1=\n")),
+            (404, json!("day 25 has no part 2"))
+        );
+    }
+}
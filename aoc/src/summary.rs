@@ -0,0 +1,145 @@
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::process::ExitCode;
+
+use aoc_common::answers::{expected, matches};
+use aoc_common::input::{day_directory, InputSource};
+use aoc_common::pool;
+use aoc_common::solution::{Answer, Solved};
+
+use crate::bench::format_duration;
+use crate::days::Day;
+
+/// A day's answers, or why it has none, and what its `answers.txt` expects.
+/// Asked for part 2 alone, a day without one has no answers, which isn't a
+/// failure.
+pub struct Outcome {
+    pub day: u8,
+    pub answers: Result<Vec<Solved>, String>,
+    expected: Option<String>,
+}
+
+impl Outcome {
+    // Whether each answer matches, if the manifest has one to compare with
+    fn verdicts(&self) -> Vec<Option<bool>> {
+        let answers = self.answers.as_deref().unwrap_or_default();
+        answers
+            .iter()
+            .map(|solved| matches(self.expected.as_deref()?, solved))
+            .collect()
+    }
+
+    pub fn failed(&self) -> bool {
+        self.answers.is_err() || self.verdicts().contains(&Some(false))
+    }
+}
+
+/// Solves every day at once on the thread pool, in the order of `days`.
+///
+/// A day that panics gets an error row rather than ending every other day's
+/// run with it.
+pub fn solve_all(days: &[Day], part: Option<u8>, source: &InputSource) -> Vec<Outcome> {
+    pool::map(days, |day| {
+        let directory = day_directory(day.number);
+        let answers = source
+            .read(&directory)
+            .map_err(|err| format!("couldn't read {source}: {err}"))
+            .and_then(|input| {
+                catch_unwind(AssertUnwindSafe(|| (day.solve)(&input, part)))
+                    .map_err(|panic| panic_message(&*panic))?
+                    .map_err(|err| format!("couldn't parse {source}: {err}"))
+            });
+
+        let file = source.path(&directory);
+        let file = file.file_name().unwrap_or_default().to_string_lossy();
+        Outcome {
+            day: day.number,
+            answers,
+            expected: expected(&directory, &file),
+        }
+    })
+}
+
+pub(crate) fn panic_message(panic: &(dyn Any + Send)) -> String {
+    let message = panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("no message");
+    format!("panicked: {message}")
+}
+
+/// Prints a row per part, with how long it took and whether it's the answer
+/// the day's `answers.txt` expects, and art on the lines after its row.
+pub fn print(outcomes: &[Outcome]) {
+    let width = outcomes
+        .iter()
+        .flat_map(|outcome| match &outcome.answers {
+            Ok(answers) => answers
+                .iter()
+                .map(|solved| cell(&solved.answer).len())
+                .collect(),
+            Err(err) => vec![err.len()],
+        })
+        .fold("answer".len(), usize::max);
+
+    println!("day  part  {:<width$}  {:>9}  status", "answer", "time");
+
+    let (mut checked, mut matched) = (0, 0);
+    for outcome in outcomes {
+        let answers = match &outcome.answers {
+            Ok(answers) => answers,
+            Err(err) => {
+                println!("{:02}   -     {err:<width$}  {:>9}  error", outcome.day, "");
+                continue;
+            }
+        };
+
+        if answers.is_empty() {
+            println!("{:02}   2     {:<width$}  {:>9}  n/a", outcome.day, "", "");
+        }
+
+        for (solved, verdict) in answers.iter().zip(outcome.verdicts()) {
+            let status = match verdict {
+                Some(true) => "ok",
+                Some(false) => "wrong",
+                None => "",
+            };
+            checked += usize::from(verdict.is_some());
+            matched += usize::from(verdict == Some(true));
+
+            let row = format!(
+                "{:02}   {:<4}  {:<width$}  {:>9}  {status}",
+                outcome.day,
+                solved.part,
+                cell(&solved.answer),
+                format_duration(u64::try_from(solved.elapsed.as_nanos()).unwrap_or(u64::MAX)),
+            );
+            println!("{}", row.trim_end());
+
+            if let Answer::Art(lines) = &solved.answer {
+                for line in lines {
+                    println!("{:11}{line}", "");
+                }
+            }
+        }
+    }
+
+    println!("{matched} of {checked} answers match answers.txt");
+}
+
+// Art goes underneath its row instead
+pub(crate) fn cell(answer: &Answer) -> String {
+    match answer {
+        Answer::Art(_) => String::new(),
+        answer => answer.to_string(),
+    }
+}
+
+pub fn exit_code(outcomes: &[Outcome]) -> ExitCode {
+    if outcomes.iter().any(Outcome::failed) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_common::generate::Rng;
+use aoc_common::parse::{number, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed<'input> = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut elves: Vec<u32> = input
+            .split("\n\n")
+            .map(|elf| elf.lines().map(number::<u32>).sum())
+            .collect::<Result<_, _>>()?;
+
+        elves.sort();
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Parsed<'_>) -> Answer {
+        let part1 = *elves.last().unwrap();
+        part1.into()
+    }
+
+    fn part2(elves: &Self::Parsed<'_>) -> Option<Answer> {
+        let part2: u32 = elves.iter().rev().take(3).sum();
+        Some(part2.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let elves: Vec<String> = (0..size)
+            .map(|_| {
+                (0..rng.range(1..=15))
+                    .map(|_| format!("{}\n", rng.range(1_000..=60_000)))
+                    .collect()
+            })
+            .collect();
+
+        elves.join("\n")
+    }
+}
@@ -0,0 +1,23 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_01::Day01;
+
+// Keeps a running total per elf, then picks the biggest ones off the end
+fn naive(input: &str) -> Vec<Answer> {
+    let mut totals = vec![0];
+    for line in input.lines() {
+        match line {
+            "" => totals.push(0),
+            calories => *totals.last_mut().unwrap() += calories.parse::<u32>().unwrap(),
+        }
+    }
+
+    totals.sort_by(|a, b| b.cmp(a));
+    let top_three: u32 = totals.iter().take(3).sum();
+    vec![totals[0].into(), top_three.into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day01, _>(1..=30, property::answers::<Day01>, naive);
+}
@@ -0,0 +1,156 @@
+use std::str::FromStr;
+
+use aoc_common::generate::Rng;
+use aoc_common::parse::{split_once, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+use Outcome::*;
+use Shape::*;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl FromStr for Shape {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "A" | "X" => Ok(Rock),
+            "B" | "Y" => Ok(Paper),
+            "C" | "Z" => Ok(Scissors),
+            _ => Err(ParseError::new(string, "a shape (A-C or X-Z)")),
+        }
+    }
+}
+
+impl Shape {
+    fn play(&self, other: &Self) -> Outcome {
+        match (self, other) {
+            (Rock, Rock) => Draw,
+            (Rock, Paper) => Lose,
+            (Rock, Scissors) => Win,
+            (Paper, Rock) => Win,
+            (Paper, Paper) => Draw,
+            (Paper, Scissors) => Lose,
+            (Scissors, Rock) => Lose,
+            (Scissors, Paper) => Win,
+            (Scissors, Scissors) => Draw,
+        }
+    }
+
+    fn score(&self) -> u32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    fn round_score(&self, other: &Self) -> u32 {
+        let outcome = self.play(other);
+        let shape_score = self.score();
+        let outcome_score = outcome.score();
+
+        shape_score + outcome_score
+    }
+
+    fn what_to_play(&self, desired_outcome: Outcome) -> Self {
+        match (self, desired_outcome) {
+            (Rock, Win) => Paper,
+            (Rock, Lose) => Scissors,
+            (Rock, Draw) => Rock,
+            (Paper, Win) => Scissors,
+            (Paper, Lose) => Rock,
+            (Paper, Draw) => Paper,
+            (Scissors, Win) => Rock,
+            (Scissors, Lose) => Paper,
+            (Scissors, Draw) => Scissors,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Outcome {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl FromStr for Outcome {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "X" => Ok(Lose),
+            "Y" => Ok(Draw),
+            "Z" => Ok(Win),
+            _ => Err(ParseError::new(string, "an outcome (X-Z)")),
+        }
+    }
+}
+
+impl Outcome {
+    fn score(&self) -> u32 {
+        match self {
+            Outcome::Win => 6,
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    /// The opponent's shape, then the second column read both as a shape and
+    /// as an outcome.
+    type Parsed<'input> = Vec<(Shape, Shape, Outcome)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let (opponent, second) = split_once(line, " ")?;
+                Ok((opponent.parse()?, second.parse()?, second.parse()?))
+            })
+            .collect()
+    }
+
+    fn part1(rounds: &Self::Parsed<'_>) -> Answer {
+        let part1: u32 = rounds
+            .iter()
+            .map(|(opponent, you, _)| you.round_score(opponent))
+            .sum();
+
+        part1.into()
+    }
+
+    fn part2(rounds: &Self::Parsed<'_>) -> Option<Answer> {
+        let part2: u32 = rounds
+            .iter()
+            .map(|&(opponent, _, desired_outcome)| {
+                let should_play = opponent.what_to_play(desired_outcome);
+                (opponent, should_play)
+            })
+            .map(|(opponent, you)| you.round_score(&opponent))
+            .sum();
+
+        Some(part2.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let opponent = rng.pick(&['A', 'B', 'C']);
+                let response = rng.pick(&['X', 'Y', 'Z']);
+                format!("{opponent} {response}\n")
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_02::Day02>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use aoc_common::generate::Rng;
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed<'input> = Vec<&'input str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| match line.find(|c: char| !c.is_ascii_alphabetic()) {
+                Some(i) => Err(ParseError::new(&line[i..], "an item (a-z or A-Z)")),
+                None => Ok(line),
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Self::Parsed<'_>) -> Answer {
+        let part1: u32 = lines
+            .iter()
+            .map(|line| {
+                let midpoint = line.len() / 2;
+                let (first, second) = line.split_at(midpoint);
+                let seen: HashSet<char> = first.chars().collect();
+
+                second.chars().find(|c| seen.contains(c)).unwrap()
+            })
+            .map(score)
+            .sum();
+
+        part1.into()
+    }
+
+    fn part2(lines: &Self::Parsed<'_>) -> Option<Answer> {
+        let part2: u32 = lines
+            .chunks(3)
+            .map(|chunk| {
+                let seen_first: HashSet<char> = chunk[0].chars().collect();
+                let seen_second: HashSet<char> = chunk[1].chars().collect();
+                let seen_third: HashSet<char> = chunk[2].chars().collect();
+
+                *seen_first
+                    .intersection(&seen_second)
+                    .find(|c| seen_third.contains(c))
+                    .unwrap()
+            })
+            .map(score)
+            .sum();
+
+        Some(part2.into())
+    }
+
+    // Groups of three rucksacks sharing one badge, where each rucksack has
+    // one item in both halves and nothing else in common with the others
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut output = String::new();
+
+        for _ in 0..size.div_ceil(3) {
+            let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+            rng.shuffle(&mut items);
+            let badge = items.pop().unwrap();
+
+            // 17 items of its own for each rucksack
+            for own in items.chunks(17) {
+                let (&shared, rest) = own.split_first().unwrap();
+                let (left_pool, right_pool) = rest.split_at(8);
+                let half = rng.index(8) + 2;
+
+                let mut left: Vec<char> = [shared, badge]
+                    .into_iter()
+                    .chain(left_pool.iter().copied().take(half - 2))
+                    .collect();
+                let mut right: Vec<char> = [shared]
+                    .into_iter()
+                    .chain(right_pool.iter().copied().take(half - 1))
+                    .collect();
+                if rng.chance(0.5) {
+                    std::mem::swap(&mut left, &mut right);
+                }
+
+                rng.shuffle(&mut left);
+                rng.shuffle(&mut right);
+                output.extend(left);
+                output.extend(right);
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+}
+
+fn score(c: char) -> u32 {
+    if c.is_uppercase() {
+        c as u32 - 64 + 26
+    } else {
+        c as u32 - 96
+    }
+}
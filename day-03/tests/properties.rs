@@ -0,0 +1,40 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_03::Day03;
+
+fn priority(item: u8) -> u32 {
+    match item {
+        b'a'..=b'z' => u32::from(item - b'a') + 1,
+        _ => u32::from(item - b'A') + 27,
+    }
+}
+
+// Searches for the shared items one at a time instead of with sets
+fn naive(input: &str) -> Vec<Answer> {
+    let rucksacks: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+
+    let part1: u32 = rucksacks
+        .iter()
+        .map(|rucksack| {
+            let (left, right) = rucksack.split_at(rucksack.len() / 2);
+            priority(*left.iter().find(|item| right.contains(item)).unwrap())
+        })
+        .sum();
+
+    let part2: u32 = rucksacks
+        .chunks(3)
+        .map(|group| {
+            let badge = group[0]
+                .iter()
+                .find(|item| group[1].contains(item) && group[2].contains(item));
+            priority(*badge.unwrap())
+        })
+        .sum();
+
+    vec![part1.into(), part2.into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day03, _>(1..=30, property::answers::<Day03>, naive);
+}
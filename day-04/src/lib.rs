@@ -0,0 +1,76 @@
+use std::ops::RangeInclusive;
+
+use aoc_common::generate::Rng;
+use aoc_common::parse::{integer, labeled, ParseError, Parser};
+use aoc_common::solution::{Answer, Solution};
+
+type Pair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed<'input> = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input
+            .lines()
+            .map(|mut line| {
+                let input = &mut line;
+                let first = range(input)?;
+                let second = labeled(",", range).parse_all(input)?;
+                Ok((first, second))
+            })
+            .collect()
+    }
+
+    fn part1(ranges: &Self::Parsed<'_>) -> Answer {
+        let part1 = ranges
+            .iter()
+            .filter(|(first, second)| full_overlap(first, second))
+            .count();
+
+        part1.into()
+    }
+
+    fn part2(ranges: &Self::Parsed<'_>) -> Option<Answer> {
+        let part2 = ranges
+            .iter()
+            .filter(|(first, second)| partial_overlap(first, second))
+            .count();
+
+        Some(part2.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut range = || {
+            let start = rng.range(1..=99);
+            format!("{start}-{}", rng.range(start..=99))
+        };
+
+        (0..size)
+            .map(|_| format!("{},{}\n", range(), range()))
+            .collect()
+    }
+}
+
+fn range(input: &mut &str) -> Result<RangeInclusive<u32>, ParseError> {
+    let start = integer(input)?;
+    let end = labeled("-", integer).parse_next(input)?;
+    Ok(RangeInclusive::new(start, end))
+}
+
+fn full_overlap(r1: &RangeInclusive<u32>, r2: &RangeInclusive<u32>) -> bool {
+    let r1_contains_r2 = r1.contains(r2.start()) && r1.contains(r2.end());
+    let r2_contains_r1 = r2.contains(r1.start()) && r2.contains(r1.end());
+
+    r1_contains_r2 || r2_contains_r1
+}
+
+fn partial_overlap(r1: &RangeInclusive<u32>, r2: &RangeInclusive<u32>) -> bool {
+    let r1_contains_r2 = r1.contains(r2.start()) || r1.contains(r2.end());
+    let r2_contains_r1 = r2.contains(r1.start()) || r2.contains(r1.end());
+
+    r1_contains_r2 || r2_contains_r1
+}
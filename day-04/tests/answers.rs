@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_04::Day04>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,28 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_04::Day04;
+
+// Compares the ends of each pair of ranges directly
+fn naive(input: &str) -> Vec<Answer> {
+    let (mut contained, mut overlapping) = (0, 0);
+    for line in input.lines() {
+        let ends: Vec<u32> = line.split(['-', ',']).map(|n| n.parse().unwrap()).collect();
+        let [a, b, c, d] = ends[..] else {
+            panic!("not a pair of ranges: {line:?}");
+        };
+
+        if (a <= c && d <= b) || (c <= a && b <= d) {
+            contained += 1;
+        }
+        if a <= d && c <= b {
+            overlapping += 1;
+        }
+    }
+
+    vec![Answer::from(contained), Answer::from(overlapping)]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day04, _>(1..=30, property::answers::<Day04>, naive);
+}
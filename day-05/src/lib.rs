@@ -0,0 +1,171 @@
+use aoc_common::generate::Rng;
+use aoc_common::parse::{integer, labeled, literal, split_once, ParseError, Parser};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    /// Parses a move between towers labeled from 1 to `heights.len()`, which
+    /// can't take more crates than its tower has, and moves them in
+    /// `heights`.
+    fn parse(mut line: &str, heights: &mut [usize]) -> Result<Self, ParseError> {
+        let input = &mut line;
+        literal("move ").parse_next(input)?;
+        let count_start = *input;
+        let count = integer(input)?;
+        let from = labeled(" from ", tower(heights.len())).parse_next(input)?;
+        let to = labeled(" to ", tower(heights.len())).parse_all(input)?;
+
+        if count > heights[from] {
+            return Err(ParseError::new(
+                count_start,
+                format!(
+                    "no more crates than tower {} has ({})",
+                    from + 1,
+                    heights[from]
+                ),
+            ));
+        }
+        heights[from] -= count;
+        heights[to] += count;
+
+        Ok(Self { count, from, to })
+    }
+}
+
+/// A 1-based tower label, as an index.
+fn tower<'a>(towers: usize) -> impl FnMut(&mut &'a str) -> Result<usize, ParseError> {
+    move |input: &mut &'a str| {
+        let label = *input;
+        match integer(input)? {
+            number @ 1.. if number <= towers => Ok(number - 1),
+            _ => Err(ParseError::new(
+                label,
+                format!("a tower label from 1 to {towers}"),
+            )),
+        }
+    }
+}
+
+type Towers = Vec<Vec<char>>;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed<'input> = (Towers, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (towers, moves) = split_once(input, "\n\n")?;
+
+        let rows: Vec<Vec<char>> = towers
+            .lines()
+            .map(|line| line.chars().skip(1).step_by(4).collect())
+            .collect();
+
+        let number_of_towers = rows
+            .last()
+            .ok_or_else(|| ParseError::new(towers, "a row of tower labels"))?
+            .len();
+        let mut towers = vec![vec![]; number_of_towers];
+
+        // Skip last row since it's the labels (1, 2, 3, etc.)
+        for row in rows.into_iter().rev().skip(1) {
+            for (tower, character) in towers.iter_mut().zip(row).filter(|(_tower, c)| *c != ' ') {
+                tower.push(character);
+            }
+        }
+
+        let mut heights: Vec<usize> = towers.iter().map(Vec::len).collect();
+        let moves: Vec<Move> = moves
+            .lines()
+            .map(|line| Move::parse(line, &mut heights))
+            .collect::<Result<_, _>>()?;
+
+        Ok((towers, moves))
+    }
+
+    fn part1((towers, moves): &Self::Parsed<'_>) -> Answer {
+        tops(&play_part1(towers.clone(), moves)).into()
+    }
+
+    fn part2((towers, moves): &Self::Parsed<'_>) -> Option<Answer> {
+        Some(tops(&play_part2(towers.clone(), moves)).into())
+    }
+
+    // Moves never take a tower's last crate, so every tower has a top at the
+    // end
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let crates: Vec<char> = ('A'..='Z').collect();
+        let mut towers: Vec<Vec<char>> = (0..size.clamp(2, 9))
+            .map(|_| (0..rng.range(2..=8)).map(|_| *rng.pick(&crates)).collect())
+            .collect();
+
+        let mut output = String::new();
+        let height = towers.iter().map(Vec::len).max().unwrap();
+        for level in (0..height).rev() {
+            let row: Vec<String> = towers
+                .iter()
+                .map(|tower| match tower.get(level) {
+                    Some(c) => format!("[{c}]"),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            output += &(row.join(" ") + "\n");
+        }
+
+        let labels: Vec<String> = (1..=towers.len()).map(|n| format!(" {n} ")).collect();
+        output += &(labels.join(" ") + "\n\n");
+
+        for _ in 0..size * 5 {
+            let from = rng.index(towers.len());
+            let to = (from + 1 + rng.index(towers.len() - 1)) % towers.len();
+            if towers[from].len() < 2 {
+                continue;
+            }
+
+            let height = towers[from].len();
+            let count = rng.index(height - 1) + 1;
+            let moved = towers[from].split_off(height - count);
+            towers[to].extend(moved);
+            output += &format!("move {count} from {} to {}\n", from + 1, to + 1);
+        }
+
+        output
+    }
+}
+
+// An empty tower has no top to show
+fn tops(towers: &Towers) -> String {
+    towers.iter().filter_map(|tower| tower.last()).collect()
+}
+
+fn play_part1(mut towers: Towers, moves: &[Move]) -> Towers {
+    for move_ in moves {
+        for _ in 0..move_.count {
+            let from = &mut towers[move_.from];
+            let top = from.pop().unwrap();
+            let to = &mut towers[move_.to];
+            to.push(top);
+        }
+    }
+
+    towers
+}
+
+fn play_part2(mut towers: Towers, moves: &[Move]) -> Towers {
+    for move_ in moves {
+        let from = &mut towers[move_.from];
+        let top = from.split_off(from.len() - move_.count);
+        let to = &mut towers[move_.to];
+        to.extend_from_slice(&top);
+    }
+
+    towers
+}
@@ -0,0 +1,52 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_05::Day05;
+
+// Reads the crates by column position, and moves them one at a time for
+// part 1 and in a single slice for part 2
+fn naive(input: &str) -> Vec<Answer> {
+    let (drawing, moves) = input.split_once("\n\n").unwrap();
+    let mut rows: Vec<&[u8]> = drawing.lines().map(str::as_bytes).collect();
+    let labels = rows.pop().unwrap();
+
+    let mut towers = vec![vec![]; labels.len().div_ceil(4)];
+    for row in rows.iter().rev() {
+        for (i, tower) in towers.iter_mut().enumerate() {
+            match row.get(i * 4 + 1) {
+                Some(&c) if c != b' ' => tower.push(c as char),
+                _ => {}
+            }
+        }
+    }
+
+    let moves: Vec<[usize; 3]> = moves
+        .lines()
+        .map(|line| {
+            let words: Vec<&str> = line.split(' ').collect();
+            [1, 3, 5].map(|i| words[i].parse().unwrap())
+        })
+        .collect();
+
+    let mut one_at_a_time = towers.clone();
+    let mut all_at_once = towers;
+    for [count, from, to] in moves {
+        for _ in 0..count {
+            let top = one_at_a_time[from - 1].pop().unwrap();
+            one_at_a_time[to - 1].push(top);
+        }
+
+        let from = &mut all_at_once[from - 1];
+        let moved = from.split_off(from.len() - count);
+        all_at_once[to - 1].extend(moved);
+    }
+
+    let tops = |towers: Vec<Vec<char>>| -> String {
+        towers.iter().map(|tower| tower.last().unwrap()).collect()
+    };
+    vec![tops(one_at_a_time).into(), tops(all_at_once).into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day05, _>(1..=20, property::answers::<Day05>, naive);
+}
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use aoc_common::generate::Rng;
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+
+// How many different letters in a row make each part's marker
+const PACKET: usize = 4;
+const MESSAGE: usize = 14;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed<'input> = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let input = input.trim_end();
+        if let Some(i) = input.find(|c: char| !c.is_ascii_lowercase()) {
+            return Err(ParseError::new(&input[i..], "a letter (a-z)"));
+        }
+
+        // A stream with part 2's marker has part 1's shorter one too
+        let chars: Vec<char> = input.chars().collect();
+        match solve(&chars, MESSAGE) {
+            Some(_) => Ok(chars),
+            None => Err(ParseError::new(
+                &input[input.len()..],
+                format!("{MESSAGE} different letters in a row"),
+            )),
+        }
+    }
+
+    fn part1(chars: &Self::Parsed<'_>) -> Answer {
+        solve(chars, PACKET).unwrap().into()
+    }
+
+    fn part2(chars: &Self::Parsed<'_>) -> Option<Answer> {
+        Some(solve(chars, MESSAGE).unwrap().into())
+    }
+
+    // Mostly a few letters over and over, ending in 14 different ones so both
+    // markers turn up
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut letters: Vec<char> = ('a'..='z').collect();
+        rng.shuffle(&mut letters);
+
+        let mut stream: String = (0..size * 10).map(|_| *rng.pick(&letters[..5])).collect();
+        stream.extend(&letters[..14]);
+        stream + "\n"
+    }
+}
+
+// Returns the number of characters processed before the first marker, if
+// there is one
+fn solve(chars: &[char], unique: usize) -> Option<usize> {
+    chars
+        .windows(unique)
+        .position(|window| window.iter().collect::<HashSet<_>>().len() == unique)
+        .map(|i| i + unique)
+}
@@ -0,0 +1,22 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_06::Day06;
+
+// Compares every pair of characters in each window
+fn naive(input: &str) -> Vec<Answer> {
+    let stream = input.trim_end().as_bytes();
+    let marker = |length: usize| {
+        let start = stream
+            .windows(length)
+            .position(|window| (0..length).all(|i| !window[i + 1..].contains(&window[i])))
+            .unwrap();
+        Answer::from(start + length)
+    };
+
+    vec![marker(4), marker(14)]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day06, _>(1..=30, property::answers::<Day06>, naive);
+}
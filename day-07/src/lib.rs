@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use aoc_common::config::{self, Parameter};
+use aoc_common::generate::Rng;
+use aoc_common::parse::{number, split_once, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Debug)]
+enum Command<'input> {
+    CdRoot,              // cd '/'
+    CdUp,                // cd ".."
+    CdDown(&'input str), // cd a
+    Ls,                  // ls
+}
+
+impl<'input> TryFrom<&'input str> for Command<'input> {
+    type Error = ParseError;
+
+    fn try_from(string: &'input str) -> Result<Self, Self::Error> {
+        match string.split_once(' ') {
+            None if string == "ls" => Ok(Command::Ls),
+            Some(("cd", "/")) => Ok(Command::CdRoot),
+            Some(("cd", "..")) => Ok(Command::CdUp),
+            Some(("cd", name)) => Ok(Command::CdDown(name)),
+            _ => Err(ParseError::new(string, "a command (`cd` or `ls`)")),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Line<'input> {
+    Cmd(Command<'input>),
+    File(usize, &'input str),
+    Dir(&'input str),
+}
+
+impl<'input> TryFrom<&'input str> for Line<'input> {
+    type Error = ParseError;
+
+    fn try_from(string: &'input str) -> Result<Self, Self::Error> {
+        let (first, rest) = split_once(string, " ")?;
+
+        match first {
+            "$" => Ok(Line::Cmd(Command::try_from(rest)?)),
+            "dir" => Ok(Line::Dir(rest)),
+            size => Ok(Line::File(number(size)?, rest)),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Directory<'input> {
+    files: HashMap<&'input str, usize>,
+    directories: HashMap<&'input str, Self>,
+}
+
+impl<'input> Directory<'input> {
+    fn add_file(&mut self, name: &'input str, size: usize) {
+        self.files.insert(name, size);
+    }
+
+    fn add_directory(&mut self, name: &'input str) {
+        self.directories.insert(name, Directory::default());
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter::new::<usize>("small_directory"),
+        Parameter::new::<usize>("disk_space"),
+        Parameter::new::<usize>("update_size"),
+    ];
+
+    // The size of every directory along with the total space used
+    type Parsed<'input> = (HashMap<String, usize>, usize);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut lines = input.lines();
+        match lines.next() {
+            Some("$ cd /") => {}
+            _ => return Err(ParseError::new(input, "\"$ cd /\"")),
+        }
+
+        let mut root = Directory::default();
+        build_filesystem(&mut root, &mut lines, true)?;
+
+        let mut sizes = HashMap::default();
+        let total_space_already_used = calculate_directory_sizes("/".into(), &root, &mut sizes);
+
+        Ok((sizes, total_space_already_used))
+    }
+
+    fn part1((sizes, _total_space_already_used): &Self::Parsed<'_>) -> Answer {
+        let small_directory = config::parameter(Self::DAY, "small_directory", 100_000);
+        let part1: usize = sizes
+            .values()
+            .copied()
+            .filter(|&size| size <= small_directory)
+            .sum();
+
+        part1.into()
+    }
+
+    fn part2((sizes, total_space_already_used): &Self::Parsed<'_>) -> Option<Answer> {
+        let total_disk_space: usize = config::parameter(Self::DAY, "disk_space", 70_000_000);
+        let space_necessary_for_update: usize =
+            config::parameter(Self::DAY, "update_size", 30_000_000);
+
+        let free_space = total_disk_space.saturating_sub(*total_space_already_used);
+        let need_to_delete = space_necessary_for_update.saturating_sub(free_space);
+
+        let part2 = sizes
+            .values()
+            .copied()
+            .filter(|&size| size >= need_to_delete)
+            .min()
+            .unwrap();
+
+        Some(part2.into())
+    }
+
+    // Between 45M and 65M used in all, since part 2 expects to need to free
+    // up some space but not more than the update takes
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut names = HashSet::new();
+        let mut name = |rng: &mut Rng| loop {
+            let name: String = (0..rng.range(1..=8))
+                .map(|_| (b'a' + rng.index(26) as u8) as char)
+                .collect();
+            if names.insert(name.clone()) {
+                break name;
+            }
+        };
+
+        // Each directory's parent is one made before it
+        let mut children = vec![vec![]; size];
+        for directory in 1..size {
+            children[rng.index(directory)].push(directory);
+        }
+        let directories: Vec<String> = (0..size).map(|_| name(rng)).collect();
+
+        let mut files: Vec<Vec<(String, u64)>> = (0..size)
+            .map(|directory| {
+                let count = rng.range(i64::from(directory == 0)..=4);
+                (0..count)
+                    .map(|_| (name(rng), rng.range(1..=1_000) as u64))
+                    .collect()
+            })
+            .collect();
+
+        let total = rng.range(45_000_000..=65_000_000) as u64;
+        let weights: u64 = files.iter().flatten().map(|(_, weight)| weight).sum();
+        for (_, size) in files.iter_mut().flatten() {
+            *size = (*size * total / weights).max(1);
+        }
+
+        let mut output = String::new();
+        write_directory(0, &directories, &children, &files, &mut output);
+        output
+    }
+}
+
+fn build_filesystem<'input>(
+    directory: &mut Directory<'input>,
+    lines: &mut impl Iterator<Item = &'input str>,
+    is_root: bool,
+) -> Result<(), ParseError> {
+    while let Some(string) = lines.next() {
+        match Line::try_from(string)? {
+            Line::Cmd(Command::Ls) => {} // no-op
+            Line::Cmd(Command::CdUp) if is_root => {
+                return Err(ParseError::new(string, "a command other than `cd ..` in /"));
+            }
+            Line::Cmd(Command::CdUp) => return Ok(()),
+            // Let's pretend this only happens on the first line
+            Line::Cmd(Command::CdRoot) => {
+                return Err(ParseError::new(string, "a command other than `cd /`"));
+            }
+            Line::Cmd(Command::CdDown(name)) => {
+                let descend_into = directory
+                    .directories
+                    .get_mut(&name)
+                    .ok_or_else(|| ParseError::new(name, "a directory listed by `ls`"))?;
+                build_filesystem(descend_into, lines, false)?;
+            }
+            Line::File(size, name) => directory.add_file(name, size),
+            Line::Dir(name) => directory.add_directory(name),
+        }
+    }
+
+    Ok(())
+}
+
+fn calculate_directory_sizes(
+    current_directory: String,
+    directory: &Directory,
+    sizes: &mut HashMap<String, usize>,
+) -> usize {
+    let size_of_files: usize = directory.files.values().sum();
+    let size_of_directories: usize = directory
+        .directories
+        .iter()
+        .map(|(dir_name, directory)| {
+            calculate_directory_sizes(format!("{current_directory}/{dir_name}"), directory, sizes)
+        })
+        .sum();
+
+    let total = size_of_files + size_of_directories;
+    sizes.insert(current_directory, total);
+    total
+}
+
+// A terminal session listing `directory` and everything under it, in the
+// order a depth first search would look
+fn write_directory(
+    directory: usize,
+    names: &[String],
+    children: &[Vec<usize>],
+    files: &[Vec<(String, u64)>],
+    output: &mut String,
+) {
+    match directory {
+        0 => *output += "$ cd /\n",
+        _ => *output += &format!("$ cd {}\n", names[directory]),
+    }
+
+    *output += "$ ls\n";
+    for &child in &children[directory] {
+        *output += &format!("dir {}\n", names[child]);
+    }
+    for (name, size) in &files[directory] {
+        *output += &format!("{size} {name}\n");
+    }
+
+    for &child in &children[directory] {
+        write_directory(child, names, children, files, output);
+        *output += "$ cd ..\n";
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_07::Day07>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_07::Day07;
+
+// Adds each file's size to every directory on the current path, rather than
+// building the tree
+fn naive(input: &str) -> Vec<Answer> {
+    let mut path: Vec<&str> = vec![];
+    let mut sizes: HashMap<Vec<&str>, usize> = HashMap::new();
+
+    for line in input.lines() {
+        let words: Vec<&str> = line.split(' ').collect();
+        match words[..] {
+            ["$", "cd", "/"] => path.clear(),
+            ["$", "cd", ".."] => {
+                path.pop();
+            }
+            ["$", "cd", name] => path.push(name),
+            ["$", "ls"] | ["dir", _] => {}
+            [size, _] => {
+                let size: usize = size.parse().unwrap();
+                for depth in 0..=path.len() {
+                    *sizes.entry(path[..depth].to_vec()).or_default() += size;
+                }
+            }
+            _ => panic!("not a line of terminal output: {line:?}"),
+        }
+    }
+
+    let small: usize = sizes.values().filter(|&&size| size <= 100_000).sum();
+    let need_to_delete = 30_000_000 - (70_000_000 - sizes[&vec![]]);
+    let smallest_big_enough = sizes
+        .values()
+        .filter(|&&size| size >= need_to_delete)
+        .min()
+        .unwrap();
+
+    vec![small.into(), (*smallest_big_enough).into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day07, _>(1..=30, property::answers::<Day07>, naive);
+}
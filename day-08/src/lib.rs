@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+use std::iter::{Repeat, Rev, Zip};
+use std::ops::{RangeFrom, RangeInclusive};
+
+use aoc_common::generate::Rng;
+use aoc_common::grid::Grid;
+use aoc_common::image::{self, Image, Rgb};
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+
+type Trees = HashSet<(usize, usize)>;
+
+#[derive(Debug, Copy, Clone)]
+enum Index {
+    Row(usize),
+    Column(usize),
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed<'input> = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Grid::parse(input, "a tree height (0-9)", |c| c.to_digit(10))
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> Answer {
+        image::export(Self::DAY, "heights", || {
+            Image::from_grid(grid, |&height| {
+                Rgb::blend(Rgb(20, 50, 20), Rgb(150, 230, 110), f64::from(height) / 9.0)
+            })
+        });
+
+        let mut visible: Trees = Trees::default();
+
+        for (row_number, row) in grid.iter_rows().enumerate() {
+            let other_coordinate = Index::Row(row_number);
+
+            // Look from the left
+            find_visible_trees(
+                row.iter().copied().enumerate(),
+                &mut visible,
+                other_coordinate,
+            );
+
+            // Look from the right
+            find_visible_trees(
+                row.iter().copied().enumerate().rev(),
+                &mut visible,
+                other_coordinate,
+            );
+        }
+
+        for column_number in 0..grid.columns() {
+            let other_coordinate = Index::Column(column_number);
+            let column = grid.column(column_number);
+
+            // Look from the top
+            find_visible_trees(
+                column.clone().copied().enumerate(),
+                &mut visible,
+                other_coordinate,
+            );
+
+            // Look from the bottom
+            find_visible_trees(
+                column.copied().enumerate().rev(),
+                &mut visible,
+                other_coordinate,
+            );
+        }
+
+        visible.len().into()
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> Option<Answer> {
+        let dimension = grid.rows();
+        let mut max_scenic_score = 0;
+
+        // Iterate over all non-edge trees
+        for (row_number, row) in grid
+            .iter_rows()
+            .enumerate()
+            .skip(1)
+            .take(dimension.saturating_sub(2))
+        {
+            for (column_number, _tree) in row
+                .iter()
+                .enumerate()
+                .skip(1)
+                .take(dimension.saturating_sub(2))
+            {
+                let scenic_score = scenic_score(grid, row_number, column_number);
+                if scenic_score > max_scenic_score {
+                    max_scenic_score = scenic_score;
+                }
+            }
+        }
+
+        Some(max_scenic_score.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let grid = Grid::from_fn(size, size, |_| {
+            char::from_digit(rng.index(10) as u32, 10).unwrap()
+        });
+        grid.to_string()
+    }
+}
+
+fn scenic_score(grid: &Grid<u32>, row_number: usize, column_number: usize) -> u32 {
+    let left = look::<Left>(grid, row_number, column_number);
+    let right = look::<Right>(grid, row_number, column_number);
+    let up = look::<Up>(grid, row_number, column_number);
+    let down = look::<Down>(grid, row_number, column_number);
+
+    left * right * up * down
+}
+
+fn find_visible_trees(
+    trees: impl IntoIterator<Item = (usize, u32)>,
+    visible: &mut Trees,
+    other_coordinate: Index,
+) {
+    let mut trees = trees.into_iter();
+    let (i, mut tallest) = trees.next().unwrap();
+    insert(i, other_coordinate, visible);
+
+    for (i, tree) in trees {
+        if tree > tallest {
+            tallest = tree;
+            insert(i, other_coordinate, visible);
+        }
+    }
+}
+
+fn insert(coordinate: usize, other_coordinate: Index, visible: &mut Trees) {
+    match other_coordinate {
+        Index::Row(row_number) => visible.insert((row_number, coordinate)),
+        Index::Column(column_number) => visible.insert((coordinate, column_number)),
+    };
+}
+
+trait Direction {
+    type Range: Iterator<Item = (usize, usize)>;
+
+    fn range(row_number: usize, column_number: usize) -> Self::Range;
+}
+
+fn look<Dir: Direction>(grid: &Grid<u32>, row_number: usize, column_number: usize) -> u32 {
+    let this_tree = grid.get((row_number, column_number)).unwrap();
+    let mut total = 0;
+
+    for (row_number, column_number) in Dir::range(row_number, column_number) {
+        match grid.get((row_number, column_number)) {
+            None => break,
+            Some(height) => {
+                total += 1;
+                if height >= this_tree {
+                    break;
+                }
+            }
+        }
+    }
+
+    total
+}
+
+struct Left;
+struct Right;
+struct Up;
+struct Down;
+
+impl Direction for Left {
+    type Range = Zip<Repeat<usize>, Rev<RangeInclusive<usize>>>;
+
+    fn range(row_number: usize, column_number: usize) -> Self::Range {
+        std::iter::repeat(row_number).zip((0..=column_number - 1).rev())
+    }
+}
+
+impl Direction for Right {
+    type Range = Zip<Repeat<usize>, RangeFrom<usize>>;
+
+    fn range(row_number: usize, column_number: usize) -> Self::Range {
+        std::iter::repeat(row_number).zip((column_number + 1)..)
+    }
+}
+
+impl Direction for Up {
+    type Range = Zip<Rev<RangeInclusive<usize>>, Repeat<usize>>;
+
+    fn range(row_number: usize, column_number: usize) -> Self::Range {
+        (0..=row_number - 1)
+            .rev()
+            .zip(std::iter::repeat(column_number))
+    }
+}
+
+impl Direction for Down {
+    type Range = Zip<RangeFrom<usize>, Repeat<usize>>;
+
+    fn range(row_number: usize, column_number: usize) -> Self::Range {
+        (row_number + 1..).zip(std::iter::repeat(column_number))
+    }
+}
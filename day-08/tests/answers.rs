@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_08::Day08>(env!("CARGO_MANIFEST_DIR"));
+}
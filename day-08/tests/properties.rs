@@ -0,0 +1,46 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_08::Day08;
+
+// Walks out from every tree in all four directions
+fn naive(input: &str) -> Vec<Answer> {
+    let grid: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+    let (rows, columns) = (grid.len() as isize, grid[0].len() as isize);
+
+    let mut visible = 0;
+    let mut best_score = 0;
+    for y in 0..rows {
+        for x in 0..columns {
+            let height = grid[y as usize][x as usize];
+            let mut seen_from_outside = false;
+            let mut score = 1;
+
+            for (dy, dx) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let (mut ny, mut nx) = (y + dy, x + dx);
+                let mut trees = 0;
+                let mut blocked = false;
+                while (0..rows).contains(&ny) && (0..columns).contains(&nx) {
+                    trees += 1;
+                    if grid[ny as usize][nx as usize] >= height {
+                        blocked = true;
+                        break;
+                    }
+                    (ny, nx) = (ny + dy, nx + dx);
+                }
+
+                seen_from_outside |= !blocked;
+                score *= trees;
+            }
+
+            visible += usize::from(seen_from_outside);
+            best_score = best_score.max(score);
+        }
+    }
+
+    vec![visible.into(), best_score.into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day08, _>(1..=20, property::answers::<Day08>, naive);
+}
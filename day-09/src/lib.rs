@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_common::animate;
+use aoc_common::config::{self, Parameter};
+use aoc_common::generate::Rng;
+use aoc_common::geometry::{Direction, Point2};
+use aoc_common::grid::Grid;
+use aoc_common::parse::{integer, labeled, word, ParseError, Parser};
+use aoc_common::solution::{Answer, Solution};
+use Direction::*;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Motion {
+    direction: Direction,
+    steps: usize,
+}
+
+impl FromStr for Motion {
+    type Err = ParseError;
+
+    fn from_str(mut line: &str) -> Result<Self, Self::Err> {
+        let input = &mut line;
+        let direction = parse_direction(word(input)?)?;
+        let steps = labeled(" ", integer).parse_all(input)?;
+
+        Ok(Self { direction, steps })
+    }
+}
+
+impl Iterator for Motion {
+    type Item = Direction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.steps == 0 {
+            None
+        } else {
+            self.steps -= 1;
+            Some(self.direction)
+        }
+    }
+}
+
+fn parse_direction(input: &str) -> Result<Direction, ParseError> {
+    match input {
+        "L" => Ok(Left),
+        "R" => Ok(Right),
+        "U" => Ok(Up),
+        "D" => Ok(Down),
+        _ => Err(ParseError::new(input, "a direction (L, R, U or D)")),
+    }
+}
+
+type Point = Point2<isize>;
+
+// Moves `tail` a step towards `head`, unless they're already touching
+fn follow(tail: Point, head: Point) -> Point {
+    if tail.chebyshev(head) <= 1 {
+        return tail;
+    }
+
+    let dy = (head.y - tail.y).signum();
+    let dx = (head.x - tail.x).signum();
+    tail.offset((dy, dx))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter::new::<usize>("part1_knots"),
+        Parameter::new::<usize>("part2_knots"),
+    ];
+
+    type Parsed<'input> = Vec<Motion>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(motions: &Self::Parsed<'_>) -> Answer {
+        let knots = config::parameter(Self::DAY, "part1_knots", 2);
+        tail_visits(motions, knots).into()
+    }
+
+    fn part2(motions: &Self::Parsed<'_>) -> Option<Answer> {
+        let knots = config::parameter(Self::DAY, "part2_knots", 10);
+        Some(tail_visits(motions, knots).into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let direction = rng.pick(&['U', 'D', 'L', 'R']);
+                format!("{direction} {}\n", rng.range(1..=20))
+            })
+            .collect()
+    }
+}
+
+// Counts the points visited by the last of `knots` knots, counting the head,
+// which always has at least one knot following it
+fn tail_visits(motions: &[Motion], knots: usize) -> usize {
+    let mut head = Point::default();
+    let mut tails = vec![Point::default(); knots.max(2) - 1];
+    let mut seen: HashSet<Point> = [Point::default()].into();
+
+    for motion in motions.iter().copied() {
+        for direction in motion {
+            head = head.step(direction);
+            tails[0] = follow(tails[0], head);
+            for i in 1..tails.len() {
+                tails[i] = follow(tails[i], tails[i - 1]);
+            }
+
+            seen.insert(tails[tails.len() - 1]);
+            animate!("{}", draw_rope(head, &tails, &seen));
+        }
+    }
+
+    seen.len()
+}
+
+// The knots over the points the tail visited, with the head as `H` and the
+// rest numbered from 1
+fn draw_rope(head: Point, tails: &[Point], seen: &HashSet<Point>) -> Grid<char> {
+    let seen = seen.iter().map(|&point| (point, '#'));
+    let knots = (1..=tails.len()).rev().map(|i| {
+        let number = char::from_digit(i as u32 % 10, 10).unwrap();
+        (tails[i - 1], number)
+    });
+
+    Grid::from_points(
+        seen.chain([(Point::default(), 's')])
+            .chain(knots)
+            .chain([(head, 'H')]),
+        '.',
+    )
+}
@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_09::Day09;
+
+// Moves every knot with plain coordinates, one step at a time
+fn tail_visits(input: &str, knots: usize) -> usize {
+    let mut rope = vec![(0_i32, 0_i32); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+
+    for line in input.lines() {
+        let (direction, steps) = line.split_once(' ').unwrap();
+        let (dx, dy) = match direction {
+            "U" => (0, 1),
+            "D" => (0, -1),
+            "L" => (-1, 0),
+            "R" => (1, 0),
+            _ => panic!("not a direction: {direction:?}"),
+        };
+
+        for _ in 0..steps.parse().unwrap() {
+            rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+            for knot in 1..knots {
+                let (ahead, behind) = (rope[knot - 1], rope[knot]);
+                if (ahead.0 - behind.0).abs() > 1 || (ahead.1 - behind.1).abs() > 1 {
+                    rope[knot] = (
+                        behind.0 + (ahead.0 - behind.0).signum(),
+                        behind.1 + (ahead.1 - behind.1).signum(),
+                    );
+                }
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+
+    visited.len()
+}
+
+fn naive(input: &str) -> Vec<Answer> {
+    vec![tail_visits(input, 2).into(), tail_visits(input, 10).into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day09, _>(1..=30, property::answers::<Day09>, naive);
+}
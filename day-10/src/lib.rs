@@ -0,0 +1,155 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc_common::generate::Rng;
+use aoc_common::grid::Grid;
+use aoc_common::parse::{number, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Add(i32),
+}
+
+impl Instruction {
+    fn cycles(&self) -> u32 {
+        match *self {
+            Instruction::Noop => 1,
+            Instruction::Add(_) => 2,
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.split_once(' ') {
+            None if input == "noop" => Ok(Self::Noop),
+            Some(("addx", value)) => Ok(Self::Add(number(value)?)),
+            _ => Err(ParseError::new(input, "an instruction (`noop` or `addx`)")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Screen {
+    grid: Grid<bool>,
+    pixel_being_drawn: Point,
+}
+
+impl Screen {
+    const WIDTH: usize = 40;
+    const HEIGHT: usize = 6;
+
+    fn advance(&mut self) {
+        self.pixel_being_drawn.advance();
+    }
+
+    fn draw(&mut self, sprite_position: i32) {
+        self.grid[(self.pixel_being_drawn.row, self.pixel_being_drawn.column)] =
+            self.pixel_being_drawn.in_range(sprite_position);
+        self.advance();
+    }
+}
+
+impl Display for Screen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pixel = |&lit: &bool| if lit { '█' } else { ' ' };
+        self.grid.display(pixel).fmt(f)
+    }
+}
+
+impl Default for Screen {
+    fn default() -> Self {
+        Self {
+            grid: Grid::new(Self::HEIGHT, Self::WIDTH, false),
+            pixel_being_drawn: Point::default(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+struct Point {
+    row: usize,
+    column: usize,
+}
+
+impl Point {
+    fn advance(&mut self) {
+        self.column = (self.column + 1) % Screen::WIDTH;
+
+        if self.column == 0 {
+            self.row = (self.row + 1) % Screen::HEIGHT;
+        }
+    }
+
+    fn in_range(&self, x: i32) -> bool {
+        (x - 1..=x + 1).contains(&(self.column as i32))
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed<'input> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(instructions: &Self::Parsed<'_>) -> Answer {
+        let (signal_strengths, _screen) = run(instructions);
+        let part1: i32 = signal_strengths.into_iter().sum();
+        part1.into()
+    }
+
+    fn part2(instructions: &Self::Parsed<'_>) -> Option<Answer> {
+        let (_signal_strengths, screen) = run(instructions);
+        Some(Answer::art(screen))
+    }
+
+    // At least the 240 cycles the screen needs
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut output = String::new();
+        let mut cycles = 0;
+        while cycles < size.max(240) {
+            if rng.chance(0.3) {
+                output += "noop\n";
+                cycles += 1;
+            } else {
+                output += &format!("addx {}\n", rng.range(-10..=10));
+                cycles += 2;
+            }
+        }
+
+        output
+    }
+}
+
+fn run(instructions: &[Instruction]) -> (Vec<i32>, Screen) {
+    let mut clock = 0;
+    let mut x = 1;
+    let mut signal_strengths = vec![];
+    let mut screen = Screen::default();
+
+    for instruction in instructions {
+        for _ in 0..instruction.cycles() {
+            clock += 1;
+            if (clock - 20) % 40 == 0 {
+                signal_strengths.push(x * clock);
+            }
+
+            screen.draw(x);
+        }
+
+        if let Instruction::Add(v) = instruction {
+            x += v;
+        }
+    }
+
+    (signal_strengths, screen)
+}
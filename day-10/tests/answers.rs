@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_10::Day10>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,40 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_10::Day10;
+
+// Lists the register's value during every cycle first, then reads both
+// answers off that
+fn naive(input: &str) -> Vec<Answer> {
+    let mut during = vec![];
+    let mut x = 1;
+    for line in input.lines() {
+        during.push(x);
+        if let Some(value) = line.strip_prefix("addx ") {
+            during.push(x);
+            x += value.parse::<i32>().unwrap();
+        }
+    }
+
+    let strength: i32 = (20..=during.len())
+        .step_by(40)
+        .map(|cycle| cycle as i32 * during[cycle - 1])
+        .sum();
+
+    // Later cycles wrap round and draw over the first ones
+    let mut screen = [[' '; 40]; 6];
+    for (i, x) in during.iter().enumerate() {
+        let column = (i % 40) as i32;
+        screen[i / 40 % 6][i % 40] = if (column - x).abs() <= 1 { '█' } else { ' ' };
+    }
+    let screen: String = screen
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect();
+
+    vec![strength.into(), Answer::art(screen)]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day10, _>(1..=300, property::answers::<Day10>, naive);
+}
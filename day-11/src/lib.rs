@@ -0,0 +1,391 @@
+use std::num::NonZeroU64;
+use std::str::FromStr;
+
+use aoc_common::config::{self, Parameter};
+use aoc_common::generate::Rng;
+use aoc_common::numeric;
+use aoc_common::parse::{
+    integer, labeled, literal, number, prefix, separated, split_once, ParseError, Parser,
+};
+use aoc_common::solution::{Answer, Implementation, Solution};
+
+#[derive(Debug, Copy, Clone)]
+struct Operation {
+    op: Op,
+    term: Term,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let rest = prefix(line, "old ")?;
+        let (op, term) = split_once(rest, " ")?;
+
+        let op = op.parse()?;
+        let term = term.parse()?;
+
+        Ok(Self { op, term })
+    }
+}
+
+impl Operation {
+    // Worry levels that outgrow an `Item` are a bug, not something to wrap
+    // around
+    fn apply(&self, item: Item) -> Item {
+        match (self.op, self.term) {
+            (Op::Add, Term::Literal(literal)) => numeric::add(item, literal),
+            (Op::Add, Term::Old) => numeric::add(item, item),
+            (Op::Mul, Term::Literal(literal)) => numeric::mul(item, literal),
+            (Op::Mul, Term::Old) => numeric::mul(item, item),
+        }
+    }
+
+    fn checked_apply(&self, item: Item) -> Option<Item> {
+        let term = match self.term {
+            Term::Literal(literal) => literal,
+            Term::Old => item,
+        };
+
+        match self.op {
+            Op::Add => item.checked_add(term),
+            Op::Mul => item.checked_mul(term),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Op {
+    Add,
+    Mul,
+}
+
+impl FromStr for Op {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Self::Add),
+            "*" => Ok(Self::Mul),
+            _ => Err(ParseError::new(s, "`+` or `*`")),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Term {
+    Literal(u64),
+    Old,
+}
+
+impl FromStr for Term {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "old" => Ok(Self::Old),
+            x => Ok(Self::Literal(number(x)?)),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Test {
+    divisible_by: u64,
+    true_monkey: usize,
+    false_monkey: usize,
+}
+
+impl Test {
+    fn which_monkey(&self, item: &Item) -> usize {
+        if item.is_multiple_of(self.divisible_by) {
+            self.true_monkey
+        } else {
+            self.false_monkey
+        }
+    }
+}
+
+type Item = u64;
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<Item>,
+    operation: Operation,
+    test: Test,
+}
+
+impl Monkey {
+    /// Parses a monkey that throws to monkeys numbered from 0 to below
+    /// `monkeys`.
+    fn parse(input: &str, monkeys: usize) -> Result<Self, ParseError> {
+        let end = &input[input.len()..];
+        let mut lines = input.lines();
+        let mut line = || lines.next().unwrap_or(end).trim_start();
+
+        let mut header = line();
+        labeled("Monkey ", integer::<usize>).parse_next(&mut header)?;
+        literal(":").parse_all(header)?;
+        let items = labeled("Starting items: ", separated(integer, ", ")).parse_all(line())?;
+        let mut operation = line();
+        literal("Operation: new = ").parse_next(&mut operation)?;
+        let operation = operation.parse()?;
+
+        let test = Test {
+            divisible_by: labeled("Test: divisible by ", divisor).parse_all(line())?,
+            true_monkey: labeled("If true: throw to monkey ", monkey(monkeys)).parse_all(line())?,
+            false_monkey: labeled("If false: throw to monkey ", monkey(monkeys))
+                .parse_all(line())?,
+        };
+
+        Ok(Self {
+            items,
+            operation,
+            test,
+        })
+    }
+
+    fn turn(&mut self, relief: &impl Relief, modulo: u64) -> Vec<(usize, Item)> {
+        let items = std::mem::take(&mut self.items);
+
+        items
+            .into_iter()
+            .map(|item| self.operation.apply(item))
+            .map(|item| relief.relief(item, modulo))
+            .map(|item| (self.test.which_monkey(&item), item))
+            .collect()
+    }
+}
+
+fn divisor(input: &mut &str) -> Result<u64, ParseError> {
+    let start = *input;
+    match integer(input)? {
+        0 => Err(ParseError::new(start, "a divisor above 0")),
+        divisor => Ok(divisor),
+    }
+}
+
+/// A monkey's number, which has to be one of the `monkeys` in the input.
+fn monkey<'a>(monkeys: usize) -> impl FnMut(&mut &'a str) -> Result<usize, ParseError> {
+    move |input: &mut &'a str| {
+        let start = *input;
+        match integer(input)? {
+            number if number < monkeys => Ok(number),
+            _ => Err(ParseError::new(
+                start,
+                format!("a monkey from 0 to {}", monkeys - 1),
+            )),
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter::new::<usize>("part1_rounds"),
+        Parameter::new::<NonZeroU64>("relief"),
+        Parameter::new::<usize>("part2_rounds"),
+    ];
+
+    type Parsed<'input> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let monkeys: Vec<&str> = input.split("\n\n").collect();
+        monkeys
+            .iter()
+            .map(|monkey| Monkey::parse(monkey, monkeys.len()))
+            .collect()
+    }
+
+    fn part1(monkeys: &Self::Parsed<'_>) -> Answer {
+        let rounds = config::parameter(Self::DAY, "part1_rounds", 20);
+        let divisor = config::parameter(Self::DAY, "relief", NonZeroU64::new(3).unwrap()).get();
+        monkey_business(monkeys.clone(), rounds, Part1 { divisor }).into()
+    }
+
+    fn part2(monkeys: &Self::Parsed<'_>) -> Option<Answer> {
+        let rounds = config::parameter(Self::DAY, "part2_rounds", 10_000);
+        Some(monkey_business(monkeys.clone(), rounds, Part2).into())
+    }
+
+    // Divisors are distinct primes so that part 2's modulus stays small, and
+    // inputs whose worry levels overflow in part 1 are thrown away
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        loop {
+            let input = write_monkeys(rng, size);
+            let monkeys = Self::parse(&input).unwrap();
+            if part1_fits(monkeys) {
+                return input;
+            }
+        }
+    }
+}
+
+/// Keeps each of part 2's items as its remainders by every monkey's divisor,
+/// where [`Day11`] keeps it modulo the product of all of them.
+pub struct Day11Residues;
+
+impl Solution for Day11Residues {
+    const DAY: u8 = 11;
+    const PARAMETERS: &'static [Parameter] = Day11::PARAMETERS;
+
+    type Parsed<'input> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Day11::parse(input)
+    }
+
+    fn part1(monkeys: &Self::Parsed<'_>) -> Answer {
+        Day11::part1(monkeys)
+    }
+
+    fn part2(monkeys: &Self::Parsed<'_>) -> Option<Answer> {
+        let rounds = config::parameter(Self::DAY, "part2_rounds", 10_000);
+        Some(residue_business(monkeys, rounds).into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        Day11::generate(rng, size)
+    }
+}
+
+pub const ALTERNATIVES: [Implementation; 1] = [Implementation::new::<Day11Residues>(
+    "residues",
+    "keeps part 2's items as remainders by each monkey's divisor",
+)];
+
+fn residue_business(monkeys: &[Monkey], rounds: usize) -> usize {
+    let divisors: Vec<u64> = monkeys
+        .iter()
+        .map(|monkey| monkey.test.divisible_by)
+        .collect();
+    let residues = |item: Item| -> Vec<Item> { divisors.iter().map(|d| item % d).collect() };
+    let mut items: Vec<Vec<Vec<Item>>> = monkeys
+        .iter()
+        .map(|monkey| monkey.items.iter().map(|&item| residues(item)).collect())
+        .collect();
+    let mut counts = vec![0; monkeys.len()];
+
+    for _ in 0..rounds {
+        for (m, monkey) in monkeys.iter().enumerate() {
+            for mut item in std::mem::take(&mut items[m]) {
+                for (residue, divisor) in item.iter_mut().zip(&divisors) {
+                    *residue = monkey.operation.apply(*residue) % divisor;
+                }
+
+                counts[m] += 1;
+                let to = match item[m] {
+                    0 => monkey.test.true_monkey,
+                    _ => monkey.test.false_monkey,
+                };
+                items[to].push(item);
+            }
+        }
+    }
+
+    counts.sort();
+    counts.into_iter().rev().take(2).product()
+}
+
+fn monkey_business(mut monkeys: Vec<Monkey>, rounds: usize, relief: impl Relief) -> usize {
+    let mut counts = vec![0; monkeys.len()];
+
+    // Whether an item is divisible by any monkey's divisor is the same for
+    // its worry level modulo a multiple of all of them, the least being their
+    // lcm
+    let modulo = numeric::lcm_of(monkeys.iter().map(|monkey| monkey.test.divisible_by));
+
+    for _ in 0..rounds {
+        for m in 0..monkeys.len() {
+            let moves: Vec<_> = monkeys[m].turn(&relief, modulo);
+
+            counts[m] += moves.len();
+
+            for (to, item) in moves {
+                monkeys[to].items.push(item);
+            }
+        }
+    }
+
+    counts.sort();
+    counts.into_iter().rev().take(2).product()
+}
+
+trait Relief {
+    fn relief(&self, item: Item, modulo: u64) -> Item;
+}
+
+struct Part1 {
+    divisor: u64,
+}
+
+struct Part2;
+
+impl Relief for Part1 {
+    fn relief(&self, item: Item, _modulo: u64) -> Item {
+        item / self.divisor
+    }
+}
+
+impl Relief for Part2 {
+    fn relief(&self, item: Item, modulo: u64) -> Item {
+        item % modulo
+    }
+}
+
+fn write_monkeys(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(3, 9);
+    let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut divisors);
+    let squarer = rng.index(count);
+
+    let monkeys: Vec<String> = (0..count)
+        .map(|m| {
+            let items: Vec<String> = (0..rng.index(size) + 1)
+                .map(|_| rng.range(50..=99).to_string())
+                .collect();
+
+            let operation = if m == squarer {
+                "old * old".to_string()
+            } else if rng.chance(0.5) {
+                format!("old + {}", rng.range(1..=8))
+            } else {
+                format!("old * {}", rng.range(2..=19))
+            };
+
+            let mut others: Vec<usize> = (0..count).filter(|&other| other != m).collect();
+            rng.shuffle(&mut others);
+
+            format!(
+                "Monkey {m}:\n  Starting items: {}\n  Operation: new = {operation}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                items.join(", "),
+                divisors[m],
+                others[0],
+                others[1 % others.len()],
+            )
+        })
+        .collect();
+
+    monkeys.join("\n")
+}
+
+// Whether part 1's worry levels, which aren't kept down by a modulus, fit in
+// an `Item` for all 20 rounds
+fn part1_fits(mut monkeys: Vec<Monkey>) -> bool {
+    for _ in 0..20 {
+        for m in 0..monkeys.len() {
+            for item in std::mem::take(&mut monkeys[m].items) {
+                let Some(item) = monkeys[m].operation.checked_apply(item) else {
+                    return false;
+                };
+                let item = item / 3;
+                let to = monkeys[m].test.which_monkey(&item);
+                monkeys[to].items.push(item);
+            }
+        }
+    }
+
+    true
+}
@@ -0,0 +1,9 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_11::Day11>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn residues_answers() {
+    aoc_common::answers::check::<day_11::Day11Residues>(env!("CARGO_MANIFEST_DIR"));
+}
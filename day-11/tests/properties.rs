@@ -0,0 +1,112 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_11::{Day11, Day11Residues};
+
+struct Monkey {
+    items: Vec<u64>,
+    operator: char,
+    // `None` for `old`
+    operand: Option<u64>,
+    divisor: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+impl Monkey {
+    fn inspect(&self, old: u64) -> u64 {
+        let operand = self.operand.unwrap_or(old);
+        match self.operator {
+            '+' => old + operand,
+            _ => old * operand,
+        }
+    }
+}
+
+fn parse(input: &str) -> Vec<Monkey> {
+    input
+        .split("\n\n")
+        .map(|monkey| {
+            let lines: Vec<&str> = monkey.lines().collect();
+            let after = |line: usize, label: &str| lines[line].split_once(label).unwrap().1;
+            let last_word = |line: usize| lines[line].rsplit(' ').next().unwrap();
+            let (operator, operand) = after(2, "old ").split_once(' ').unwrap();
+
+            Monkey {
+                items: after(1, ": ")
+                    .split(", ")
+                    .map(|item| item.parse().unwrap())
+                    .collect(),
+                operator: operator.chars().next().unwrap(),
+                operand: operand.parse().ok(),
+                divisor: last_word(3).parse().unwrap(),
+                if_true: last_word(4).parse().unwrap(),
+                if_false: last_word(5).parse().unwrap(),
+            }
+        })
+        .collect()
+}
+
+fn monkey_business(mut inspections: Vec<usize>) -> usize {
+    inspections.sort_by(|a, b| b.cmp(a));
+    inspections[0] * inspections[1]
+}
+
+// Part 2 keeps each item as its remainder for every monkey's divisor instead
+// of modulo their product
+fn naive(input: &str) -> Vec<Answer> {
+    let monkeys = parse(input);
+
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|monkey| monkey.items.clone()).collect();
+    let mut inspections = vec![0; monkeys.len()];
+    for _ in 0..20 {
+        for (m, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[m]) {
+                inspections[m] += 1;
+                let item = monkey.inspect(item) / 3;
+                let to = match item % monkey.divisor {
+                    0 => monkey.if_true,
+                    _ => monkey.if_false,
+                };
+                items[to].push(item);
+            }
+        }
+    }
+    let part1 = monkey_business(inspections);
+
+    let mut items: Vec<Vec<Vec<u64>>> = monkeys
+        .iter()
+        .map(|monkey| {
+            let remainders = |item| monkeys.iter().map(|by| item % by.divisor).collect();
+            monkey.items.iter().map(remainders).collect()
+        })
+        .collect();
+    let mut inspections = vec![0; monkeys.len()];
+    for _ in 0..10_000 {
+        for (m, monkey) in monkeys.iter().enumerate() {
+            for mut item in std::mem::take(&mut items[m]) {
+                inspections[m] += 1;
+                for (remainder, by) in item.iter_mut().zip(&monkeys) {
+                    *remainder = monkey.inspect(*remainder) % by.divisor;
+                }
+                let to = match item[m] {
+                    0 => monkey.if_true,
+                    _ => monkey.if_false,
+                };
+                items[to].push(item);
+            }
+        }
+    }
+    let part2 = monkey_business(inspections);
+
+    vec![part1.into(), part2.into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day11, _>(1..=9, property::answers::<Day11>, naive);
+}
+
+#[test]
+fn residues_agree_with_naive() {
+    property::agree::<Day11Residues, _>(1..=9, property::answers::<Day11Residues>, naive);
+}
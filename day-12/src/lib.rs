@@ -0,0 +1,142 @@
+use aoc_common::generate::Rng;
+use aoc_common::geometry::Point2;
+use aoc_common::grid::{Grid, Position};
+use aoc_common::image::{self, Image, Rgb};
+use aoc_common::parse::ParseError;
+use aoc_common::search::{astar, dijkstra};
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::trace;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Parsed<'input> = Grid<i8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let grid = Grid::parse(input, "a height", height)?;
+
+        let end = &input[input.len()..];
+        for (target, expected) in [(0, "a start (`S`)"), (27, "an end (`E`)")] {
+            if find_all(target, &grid).is_empty() {
+                return Err(ParseError::new(end, expected));
+            }
+        }
+
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> Answer {
+        let start = find(0, grid);
+        let end = find(27, grid);
+
+        let distance_to_start = |&point: &Position| Point2::from(point).manhattan(start.into());
+        let search = astar(end, descend(grid), distance_to_start, |&point| {
+            point == start
+        });
+
+        let route = search.path(&start).unwrap();
+        trace!(Part, "route", "\n{}", draw_route(grid, &route));
+        image::export(Self::DAY, "route", || paint_route(grid, &route));
+        search.goal_cost().unwrap().into()
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> Option<Answer> {
+        let end = find(27, grid);
+
+        // The first `a` (or `S`) found is the closest
+        let search = dijkstra(end, descend(grid), |&point| grid[point] <= 1);
+
+        let start = search.goal.unwrap();
+        trace!(
+            Part,
+            "route",
+            "\n{}",
+            draw_route(grid, &search.path(&start).unwrap())
+        );
+        Some(search.goal_cost().unwrap().into())
+    }
+
+    // A climb along the top row and down the right edge that's never too
+    // steep, so there's always a way from `S` to `E`
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let rows = size.max(3);
+        let columns = (size * 3).max(28);
+        let mut grid = Grid::from_fn(rows, columns, |_| (b'a' + rng.index(26) as u8) as char);
+
+        let route: Vec<Position> = (0..columns)
+            .map(|x| (0, x))
+            .chain((1..rows).map(|y| (y, columns - 1)))
+            .collect();
+        for (i, &position) in route.iter().enumerate() {
+            let to_go = route.len() - 1 - i;
+            grid[position] = match (i, to_go) {
+                (0, _) => 'S',
+                (_, 0) => 'E',
+                _ => (b'z' - (to_go - 1).min(25) as u8) as char,
+            };
+        }
+
+        grid.to_string()
+    }
+}
+
+// Steps walking back down from the end, the reverse of climbing up to it
+fn descend(grid: &Grid<i8>) -> impl Fn(&Position) -> Vec<(Position, usize)> + '_ {
+    |&point| {
+        grid.neighbors4(point)
+            // Too tall to climb from there
+            .filter(|&neighbor| grid[point] - grid[neighbor] <= 1)
+            .map(|neighbor| (neighbor, 1))
+            .collect()
+    }
+}
+
+fn height(c: char) -> Option<i8> {
+    match c {
+        'S' => Some(0),
+        'E' => Some(27),
+        'a'..='z' => Some(c as i8 - 96),
+        _ => None,
+    }
+}
+
+fn find(target: i8, grid: &Grid<i8>) -> Position {
+    grid.iter()
+        .find(|&(_, &height)| height == target)
+        .map(|(point, _)| point)
+        .unwrap_or_else(|| unreachable!("target ({}) not found", target))
+}
+
+fn find_all(target: i8, grid: &Grid<i8>) -> Vec<Position> {
+    grid.iter()
+        .filter(|&(_, &height)| height == target)
+        .map(|(point, _)| point)
+        .collect()
+}
+
+// The map shaded from low to high, with the route in red
+fn paint_route(grid: &Grid<i8>, route: &[Position]) -> Image {
+    let mut colors =
+        grid.map(|&height| Rgb::blend(Rgb(30, 60, 120), Rgb::WHITE, f64::from(height) / 27.0));
+    for &point in route {
+        colors[point] = Rgb(220, 40, 40);
+    }
+
+    Image::from_grid(&colors, |&color| color)
+}
+
+// The map with only the squares on the route left in
+fn draw_route(grid: &Grid<i8>, route: &[Position]) -> String {
+    let mut map = grid.map(|_| '.');
+    for &point in route {
+        map[point] = match grid[point] {
+            0 => 'S',
+            27 => 'E',
+            height => (height as u8 + 96) as char,
+        };
+    }
+
+    map.to_string()
+}
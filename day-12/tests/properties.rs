@@ -0,0 +1,60 @@
+use std::collections::VecDeque;
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_12::Day12;
+
+// One breadth first search down from `E`, which finds the way from `S` and
+// from every `a` at once
+fn naive(input: &str) -> Vec<Answer> {
+    let grid: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+    let height = |(y, x): (usize, usize)| match grid[y][x] {
+        b'S' => b'a',
+        b'E' => b'z',
+        h => h,
+    };
+    let find = |c: u8| {
+        (0..grid.len())
+            .flat_map(|y| (0..grid[y].len()).map(move |x| (y, x)))
+            .find(|&(y, x)| grid[y][x] == c)
+            .unwrap()
+    };
+
+    let mut steps = vec![vec![usize::MAX; grid[0].len()]; grid.len()];
+    let end = find(b'E');
+    steps[end.0][end.1] = 0;
+    let mut queue = VecDeque::from([end]);
+    while let Some((y, x)) = queue.pop_front() {
+        let neighbors = [
+            (y.wrapping_sub(1), x),
+            (y + 1, x),
+            (y, x.wrapping_sub(1)),
+            (y, x + 1),
+        ];
+        for (ny, nx) in neighbors {
+            if ny < grid.len()
+                && nx < grid[0].len()
+                && steps[ny][nx] == usize::MAX
+                && height((ny, nx)) + 1 >= height((y, x))
+            {
+                steps[ny][nx] = steps[y][x] + 1;
+                queue.push_back((ny, nx));
+            }
+        }
+    }
+
+    let start = find(b'S');
+    let from_any_a = (0..grid.len())
+        .flat_map(|y| (0..grid[y].len()).map(move |x| (y, x)))
+        .filter(|&position| height(position) == b'a')
+        .map(|(y, x)| steps[y][x])
+        .min()
+        .unwrap();
+
+    vec![steps[start.0][start.1].into(), from_any_a.into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day12, _>(1..=20, property::answers::<Day12>, naive);
+}
@@ -0,0 +1,160 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use aoc_common::generate::Rng;
+use aoc_common::parse::{number, prefix, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    Number(u32),
+    List(Vec<Packet>),
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (packet, rest) = parse_list(prefix(input, "[")?)?;
+        if !rest.is_empty() {
+            return Err(ParseError::new(rest, "end of line"));
+        }
+
+        Ok(packet)
+    }
+}
+
+// Parses a list whose `[` has already been consumed, returning whatever follows its `]`
+fn parse_list(mut rest: &str) -> Result<(Packet, &str), ParseError> {
+    let mut list = vec![];
+
+    loop {
+        if let Some(after) = rest.strip_prefix(']') {
+            return Ok((Packet::List(list), after));
+        }
+        if rest.is_empty() {
+            return Err(ParseError::new(rest, r#""]" to close the list"#));
+        }
+
+        if !list.is_empty() {
+            rest = rest
+                .strip_prefix(',')
+                .ok_or_else(|| ParseError::new(rest, r#""," or "]""#))?;
+        }
+
+        let (packet, after) = match rest.strip_prefix('[') {
+            Some(after) => parse_list(after)?,
+            None => {
+                let digits = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                let (digits, after) = rest.split_at(digits);
+                (Packet::Number(number(digits)?), after)
+            }
+        };
+
+        list.push(packet);
+        rest = after;
+    }
+}
+
+impl Packet {
+    fn wrap_in_list(&self) -> Self {
+        Self::List(vec![self.clone()])
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Number(a), Packet::Number(b)) => a.cmp(b),
+            (Packet::Number(_), Packet::List(_)) => self.wrap_in_list().cmp(other),
+            (Packet::List(_), Packet::Number(_)) => self.cmp(&other.wrap_in_list()),
+            (Packet::List(left), Packet::List(right)) => left.cmp(right),
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Parsed<'input> = Vec<(Packet, Packet)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input
+            .split("\n\n")
+            .map(|pair| {
+                let end = &pair[pair.len()..];
+                let mut lines = pair.lines();
+                let a = lines.next().unwrap_or(end).parse()?;
+                let b = lines.next().unwrap_or(end).parse()?;
+                Ok((a, b))
+            })
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Parsed<'_>) -> Answer {
+        let orderings = pairs.iter().map(|(a, b)| a.cmp(b));
+
+        let part1: u32 = (1..)
+            .zip(orderings)
+            .filter(|(_, ordering)| *ordering == Ordering::Less)
+            .map(|(i, _)| i)
+            .sum();
+
+        part1.into()
+    }
+
+    fn part2(pairs: &Self::Parsed<'_>) -> Option<Answer> {
+        let mut all_packets: Vec<_> = pairs.iter().cloned().flat_map(|(a, b)| [a, b]).collect();
+
+        let two = Packet::List(vec![Packet::List(vec![Packet::Number(2)])]);
+        let six = Packet::List(vec![Packet::List(vec![Packet::Number(6)])]);
+        all_packets.push(two.clone());
+        all_packets.push(six.clone());
+
+        all_packets.sort();
+
+        let two_index = all_packets
+            .iter()
+            .position(|packet| *packet == two)
+            .unwrap()
+            + 1;
+        let six_index = all_packets
+            .iter()
+            .position(|packet| *packet == six)
+            .unwrap()
+            + 1;
+
+        Some((two_index * six_index).into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let pairs: Vec<String> = (0..size)
+            .map(|_| format!("{}\n{}\n", random_packet(rng, 0), random_packet(rng, 0)))
+            .collect();
+        pairs.join("\n")
+    }
+}
+
+fn random_packet(rng: &mut Rng, depth: usize) -> String {
+    let items: Vec<String> = (0..rng.range(0..=4))
+        .map(|_| {
+            if depth < 3 && rng.chance(0.3) {
+                random_packet(rng, depth + 1)
+            } else {
+                rng.range(0..=10).to_string()
+            }
+        })
+        .collect();
+
+    format!("[{}]", items.join(","))
+}
@@ -0,0 +1,85 @@
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_13::Day13;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Packet {
+    Number(u32),
+    List(Vec<Packet>),
+}
+
+fn parse(chars: &mut Peekable<Chars>) -> Packet {
+    if chars.next_if_eq(&'[').is_none() {
+        let mut number = 0;
+        while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+            number = number * 10 + digit.to_digit(10).unwrap();
+        }
+        return Packet::Number(number);
+    }
+
+    let mut list = vec![];
+    while chars.next_if_eq(&']').is_none() {
+        chars.next_if_eq(&',');
+        list.push(parse(chars));
+    }
+    Packet::List(list)
+}
+
+// Follows the puzzle's rules to the letter, turning numbers into lists as it
+// goes
+fn compare(left: &Packet, right: &Packet) -> Ordering {
+    match (left, right) {
+        (Packet::Number(left), Packet::Number(right)) => left.cmp(right),
+        (Packet::Number(_), Packet::List(_)) => compare(&Packet::List(vec![left.clone()]), right),
+        (Packet::List(_), Packet::Number(_)) => compare(left, &Packet::List(vec![right.clone()])),
+        (Packet::List(left), Packet::List(right)) => {
+            for (left, right) in left.iter().zip(right) {
+                match compare(left, right) {
+                    Ordering::Equal => {}
+                    ordering => return ordering,
+                }
+            }
+            left.len().cmp(&right.len())
+        }
+    }
+}
+
+// Sorts every packet with the dividers instead of counting what comes
+// before them
+fn naive(input: &str) -> Vec<Answer> {
+    let mut packets: Vec<Packet> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse(&mut line.chars().peekable()))
+        .collect();
+
+    let in_order: usize = packets
+        .chunks(2)
+        .enumerate()
+        .filter(|(_, pair)| compare(&pair[0], &pair[1]) == Ordering::Less)
+        .map(|(i, _)| i + 1)
+        .sum();
+
+    let dividers = ["[[2]]", "[[6]]"].map(|divider| parse(&mut divider.chars().peekable()));
+    packets.extend(dividers.clone());
+    packets.sort_by(compare);
+    let decoder_key: usize = dividers
+        .iter()
+        .map(|divider| {
+            // Packets equal to a divider stay ahead of it, since the sort is
+            // stable
+            packets.iter().position(|packet| packet == divider).unwrap() + 1
+        })
+        .product();
+
+    vec![in_order.into(), decoder_key.into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day13, _>(1..=30, property::answers::<Day13>, naive);
+}
@@ -0,0 +1,274 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use aoc_common::animate;
+use aoc_common::generate::Rng;
+use aoc_common::geometry::{Direction::*, Point2};
+use aoc_common::grid::Grid;
+use aoc_common::image::{self, Image, Rgb};
+use aoc_common::parse::{integer, labeled, separated, ParseError, Parser};
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::trace;
+
+use Tile::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Air => '.',
+            Rock => '#',
+            Sand => 'o',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+type Point = Point2<usize>;
+
+// Where the sand pours in from
+const SOURCE: Point = Point::new(0, 500);
+
+fn point(input: &mut &str) -> Result<Point, ParseError> {
+    let x = integer(input)?;
+    let y = labeled(",", integer).parse_next(input)?;
+
+    Ok(Point::new(y, x))
+}
+
+// The points from `start` up to, but not including, `end`
+fn between(start: Point, end: Point) -> Vec<Point> {
+    match (start.x.cmp(&end.x), start.y.cmp(&end.y)) {
+        (Ordering::Equal, Ordering::Less) => {
+            (start.y..end.y).map(|y| Point { y, ..start }).collect()
+        }
+        (Ordering::Equal, _) => (end.y + 1..=start.y)
+            .map(|y| Point { y, ..start })
+            .collect(),
+        (Ordering::Less, _) => (start.x..end.x).map(|x| Point { x, ..start }).collect(),
+        (Ordering::Greater, _) => (end.x + 1..=start.x)
+            .map(|x| Point { x, ..start })
+            .collect(),
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Parsed<'input> = (Grid<Tile>, usize);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let paths: Vec<Vec<Point>> = input
+            .lines()
+            .map(|line| separated(point, " -> ").parse_all(line))
+            .collect::<Result<_, _>>()?;
+
+        let points = paths.iter().flatten();
+        let (Some(lowest), Some(rightmost)) = (
+            points.clone().map(|point| point.y).max(),
+            points.map(|point| point.x).max(),
+        ) else {
+            return Err(ParseError::new(input, "a path of rock"));
+        };
+
+        // Leave room for part 2's floor and the sand piling up on it, which
+        // spreads out one column per row below the source
+        let rows = lowest + 3;
+        let columns = rightmost.max(500 + rows) + 1;
+        let mut grid = Grid::new(rows, columns, Air);
+        for path in paths {
+            draw_rocks(&mut grid, path.into_iter());
+        }
+
+        let abyss = find_lowest_rock(&grid);
+        Ok((grid, abyss))
+    }
+
+    fn part1((grid, abyss): &Self::Parsed<'_>) -> Answer {
+        let mut grid = grid.clone();
+        let abyss = *abyss;
+
+        let mut i: usize = 0;
+        while let Some(point) = drop_sand(&mut grid, abyss) {
+            i += 1;
+            trace!(Round, "sand", "{i} came to rest at {point}");
+            animate!("sand {i}\n{}", draw_cave(&grid));
+        }
+
+        trace!(Part, "cave", "\n{}", draw_cave(&grid));
+        image::export(Self::DAY, "sand-part1", || paint_cave(&grid));
+        i.into()
+    }
+
+    fn part2((grid, abyss): &Self::Parsed<'_>) -> Option<Answer> {
+        let mut grid = grid.clone();
+        let abyss = abyss + 2;
+
+        // Draw floor
+        let floor_start = Point::new(abyss, 0);
+        let floor_end = Point::new(abyss, grid.columns());
+        let path = between(floor_start, floor_end);
+        draw_rocks(&mut grid, path.into_iter());
+
+        let mut i: usize = 0;
+        while let Some(point) = drop_sand(&mut grid, abyss) {
+            i += 1;
+            trace!(Round, "sand", "{i} came to rest at {point}");
+            animate!("sand {i}\n{}", draw_cave(&grid));
+            if point == SOURCE {
+                break;
+            }
+        }
+
+        trace!(Part, "cave", "\n{}", draw_cave(&grid));
+        image::export(Self::DAY, "sand-part2", || paint_cave(&grid));
+        Some(i.into())
+    }
+
+    // Paths of rock spread out below the sand's source
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Sand only piles up to the source over rock as wide as it is deep, so
+        // keeping the rock deeper than it is wide means part 1 always ends
+        let spread = (size as i64 * 2 + 5).min(150);
+        let top = spread + 13;
+
+        (0..size)
+            .map(|_| {
+                let mut x = rng.range(500 - spread..=500 + spread);
+                let mut y = rng.range(top..=top + spread);
+                let mut corners = vec![format!("{x},{y}")];
+                let horizontal = rng.chance(0.5);
+
+                for i in 0..rng.range(1..=4) {
+                    let length = rng.range(-6..=6);
+                    if (i % 2 == 0) == horizontal {
+                        x += length;
+                    } else {
+                        y = (y + length).clamp(top, top + spread);
+                    }
+                    corners.push(format!("{x},{y}"));
+                }
+
+                corners.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
+
+fn draw_rocks(grid: &mut Grid<Tile>, mut path: impl Iterator<Item = Point>) {
+    let mut start = path.next().unwrap();
+
+    for end in path {
+        for point in between(start, end) {
+            draw_tile(grid, point, Rock);
+        }
+
+        start = end;
+    }
+
+    draw_tile(grid, start, Rock);
+}
+
+fn draw_tile(grid: &mut Grid<Tile>, point: Point, tile: Tile) {
+    grid[point] = tile;
+}
+
+// The cave cropped to the columns with rock or sand in them, as the grid is
+// far wider than the part that's used. Part 2's floor spans every column, so
+// it doesn't count.
+fn crop(grid: &Grid<Tile>) -> Grid<Tile> {
+    let used = || {
+        grid.iter_rows()
+            .filter(|row| row.iter().any(|&tile| tile != Rock))
+            .flat_map(|row| (0..row.len()).filter(|&x| row[x] != Air))
+    };
+    let (Some(left), Some(right)) = (used().min(), used().max()) else {
+        return Grid::new(0, 0, Air);
+    };
+
+    Grid::from_fn(grid.rows(), right - left + 1, |(y, x)| grid[(y, left + x)])
+}
+
+fn draw_cave(grid: &Grid<Tile>) -> String {
+    crop(grid).to_string()
+}
+
+fn paint_cave(grid: &Grid<Tile>) -> Image {
+    Image::from_grid(&crop(grid), |tile| match tile {
+        Air => Rgb(25, 20, 30),
+        Rock => Rgb(110, 110, 120),
+        Sand => Rgb(230, 190, 90),
+    })
+}
+
+fn drop_sand(grid: &mut Grid<Tile>, abyss: usize) -> Option<Point> {
+    find_resting_place(grid, abyss).tap(|&point| grid[point] = Sand)
+}
+
+fn lookup(grid: &Grid<Tile>, point: Point) -> Tile {
+    grid[point]
+}
+
+fn find_resting_place(grid: &Grid<Tile>, abyss: usize) -> Option<Point> {
+    let mut point = SOURCE;
+
+    loop {
+        if point.y == abyss {
+            return None;
+        }
+
+        let down = point.step(Down);
+        if lookup(grid, down) == Air {
+            point = down;
+            continue;
+        }
+
+        let down_left = down.step(Left);
+        if lookup(grid, down_left) == Air {
+            point = down_left;
+            continue;
+        }
+
+        let down_right = down.step(Right);
+        if lookup(grid, down_right) == Air {
+            point = down_right;
+            continue;
+        }
+
+        return Some(point);
+    }
+}
+
+// Anything below the lowest rock will fall into the abyss
+fn find_lowest_rock(grid: &Grid<Tile>) -> usize {
+    grid.iter_rows()
+        .rposition(|row| row.contains(&Rock))
+        .unwrap()
+}
+
+trait OptionExt<T> {
+    fn tap(self, f: impl FnOnce(&T)) -> Self;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn tap(self, f: impl FnOnce(&T)) -> Self {
+        if let Some(inner) = self.as_ref() {
+            f(inner);
+        }
+
+        self
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_14::Day14>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_14::Day14;
+
+// Drops grains into a set of blocked points, one step at a time, until one
+// falls past the lowest rock or, with a floor, the source fills up
+fn pour(mut blocked: HashSet<(i32, i32)>, lowest: i32, floor: bool) -> usize {
+    let mut grains = 0;
+    loop {
+        let (mut x, mut y) = (500, 0);
+        loop {
+            if floor && y == lowest + 1 {
+                break;
+            }
+            if !floor && y > lowest {
+                return grains;
+            }
+
+            match [x, x - 1, x + 1]
+                .into_iter()
+                .find(|&next| !blocked.contains(&(next, y + 1)))
+            {
+                Some(next) => (x, y) = (next, y + 1),
+                None => break,
+            }
+        }
+
+        blocked.insert((x, y));
+        grains += 1;
+        if (x, y) == (500, 0) {
+            return grains;
+        }
+    }
+}
+
+fn naive(input: &str) -> Vec<Answer> {
+    let mut rock = HashSet::new();
+    for line in input.lines() {
+        let corners: Vec<(i32, i32)> = line
+            .split(" -> ")
+            .map(|corner| {
+                let (x, y) = corner.split_once(',').unwrap();
+                (x.parse().unwrap(), y.parse().unwrap())
+            })
+            .collect();
+
+        for pair in corners.windows(2) {
+            let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+            for x in x1.min(x2)..=x1.max(x2) {
+                for y in y1.min(y2)..=y1.max(y2) {
+                    rock.insert((x, y));
+                }
+            }
+        }
+    }
+
+    let lowest = rock.iter().map(|&(_, y)| y).max().unwrap();
+    vec![
+        pour(rock.clone(), lowest, false).into(),
+        pour(rock, lowest, true).into(),
+    ]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day14, _>(1..=15, property::answers::<Day14>, naive);
+}
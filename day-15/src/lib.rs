@@ -0,0 +1,169 @@
+use std::str::FromStr;
+
+use fnv::FnvHashSet as HashSet;
+
+use aoc_common::config::{self, Parameter};
+use aoc_common::generate::Rng;
+use aoc_common::geometry::Point2;
+use aoc_common::parse::{integer, labeled, ParseError, Parser};
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::trace;
+
+type Point = Point2<isize>;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Sensor {
+    position: Point,
+    closest_beacon: Point,
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(mut input: &str) -> Result<Self, Self::Err> {
+        let input = &mut input;
+        let sensor_x = labeled("Sensor at x=", integer).parse_next(input)?;
+        let sensor_y = labeled(", y=", integer).parse_next(input)?;
+        let beacon_x = labeled(": closest beacon is at x=", integer).parse_next(input)?;
+        let beacon_y = labeled(", y=", integer).parse_all(input)?;
+
+        Ok(Self {
+            position: Point::new(sensor_y, sensor_x),
+            closest_beacon: Point::new(beacon_y, beacon_x),
+        })
+    }
+}
+
+impl Sensor {
+    fn manhattan_distance_to_closest_beacon(&self) -> usize {
+        self.position.manhattan(self.closest_beacon)
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        self.position.manhattan(point) <= self.manhattan_distance_to_closest_beacon()
+    }
+
+    fn cells_in_row_where_beacon_cannot_be(&self, target_row: isize) -> Vec<Point> {
+        let distance_to_beacon = self.manhattan_distance_to_closest_beacon();
+        let distance_to_target_row = self.position.y.abs_diff(target_row);
+
+        if distance_to_beacon < distance_to_target_row {
+            return vec![];
+        }
+
+        let remaining_steps = (distance_to_beacon - distance_to_target_row) as isize;
+        let x = self.position.x;
+
+        ((x - remaining_steps)..=(x + remaining_steps))
+            .map(|x| Point::new(target_row, x))
+            .collect()
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter::new::<isize>("row"),
+        Parameter::new::<isize>("limit"),
+    ];
+
+    type Parsed<'input> = Vec<Sensor>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(sensors: &Self::Parsed<'_>) -> Answer {
+        let target_row = config::parameter(Self::DAY, "row", 2_000_000);
+
+        let beacons: HashSet<Point> = sensors.iter().map(|sensor| sensor.closest_beacon).collect();
+
+        let mut cells_in_target_row: HashSet<Point> = HashSet::default();
+        for sensor in sensors.iter() {
+            for cell in sensor.cells_in_row_where_beacon_cannot_be(target_row) {
+                cells_in_target_row.insert(cell);
+            }
+        }
+
+        let cells_in_target_row = cells_in_target_row.difference(&beacons).count();
+
+        cells_in_target_row.into()
+    }
+
+    fn part2(sensors: &Self::Parsed<'_>) -> Option<Answer> {
+        let limit = config::parameter(Self::DAY, "limit", 4_000_000);
+        let crossings = crossings(sensors, limit);
+        let answer = crossings
+            .iter()
+            .copied()
+            .find(|&point| !sensors.iter().any(|sensor| sensor.contains(point)))
+            .expect("every position is in some sensor's range");
+        trace!(
+            Part,
+            "gap",
+            "{answer} is the only position out of range, of {} crossings",
+            crossings.len()
+        );
+
+        Some(tuning_frequency(answer).into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let (x, y) = (rng.range(0..=4_000_000), rng.range(0..=4_000_000));
+                let beacon_x = x + rng.range(-500_000..=500_000);
+                let beacon_y = y + rng.range(-500_000..=500_000);
+                format!("Sensor at x={x}, y={y}: closest beacon is at x={beacon_x}, y={beacon_y}\n")
+            })
+            .collect()
+    }
+}
+
+// The one position no sensor reaches has a sensor's range or the edge of the
+// search area next to it on every side, so it's where two of their edges
+// cross. The ranges' edges run diagonally, each one keeping x + y or x - y the
+// same all along it.
+fn crossings(sensors: &[Sensor], limit: isize) -> Vec<Point> {
+    let mut sums = vec![];
+    let mut differences = vec![];
+    for sensor in sensors {
+        let (x, y) = (sensor.position.x, sensor.position.y);
+        let reach = sensor.manhattan_distance_to_closest_beacon() as isize + 1;
+        sums.extend([x + y - reach, x + y + reach]);
+        differences.extend([x - y - reach, x - y + reach]);
+    }
+
+    let mut points = vec![];
+    for &sum in &sums {
+        for &difference in &differences {
+            if (sum + difference) % 2 == 0 {
+                points.push(Point::new((sum - difference) / 2, (sum + difference) / 2));
+            }
+        }
+    }
+    for edge in [0, limit] {
+        for &sum in &sums {
+            points.extend([Point::new(sum - edge, edge), Point::new(edge, sum - edge)]);
+        }
+        for &difference in &differences {
+            points.extend([
+                Point::new(edge - difference, edge),
+                Point::new(edge, edge + difference),
+            ]);
+        }
+        for corner in [0, limit] {
+            points.push(Point::new(edge, corner));
+        }
+    }
+
+    let area = 0..=limit;
+    points.retain(|point| area.contains(&point.x) && area.contains(&point.y));
+    points
+}
+
+fn tuning_frequency(point: Point) -> isize {
+    point.x * 4_000_000 + point.y
+}
@@ -0,0 +1,50 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_15::Day15;
+
+// Merges each sensor's stretch of the row instead of listing its cells. Part 2
+// only knows the real input's answer, so only part 1 is compared
+fn naive(input: &str) -> Vec<Answer> {
+    let row = 2_000_000;
+    let mut stretches = vec![];
+    let mut beacons_in_row = vec![];
+
+    for line in input.lines() {
+        let numbers: Vec<i64> = line
+            .split(|c: char| c != '-' && !c.is_ascii_digit())
+            .filter(|n| !n.is_empty())
+            .map(|n| n.parse().unwrap())
+            .collect();
+        let [sensor_x, sensor_y, beacon_x, beacon_y] = numbers[..] else {
+            panic!("not a sensor: {line:?}");
+        };
+
+        let reach = (sensor_x - beacon_x).abs() + (sensor_y - beacon_y).abs();
+        let half_width = reach - (sensor_y - row).abs();
+        if half_width >= 0 {
+            stretches.push((sensor_x - half_width, sensor_x + half_width));
+        }
+        if beacon_y == row && !beacons_in_row.contains(&beacon_x) {
+            beacons_in_row.push(beacon_x);
+        }
+    }
+
+    stretches.sort();
+    let mut covered = 0;
+    let mut next_uncovered = i64::MIN;
+    for (start, end) in stretches {
+        let start = start.max(next_uncovered);
+        if start <= end {
+            covered += end - start + 1;
+            next_uncovered = end + 1;
+        }
+    }
+
+    vec![(covered - beacons_in_row.len() as i64).into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    let part1 = |input: &str| property::answers::<Day15>(input)[..1].to_vec();
+    property::agree::<Day15, _>(1..=3, part1, naive);
+}
@@ -0,0 +1,274 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::bitset::BitSet;
+use aoc_common::config::{self, Parameter};
+use aoc_common::generate::Rng;
+use aoc_common::parse::{either, integer, labeled, literal, separated, word, ParseError, Parser};
+use aoc_common::search::floyd_warshall;
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Valve<'input> {
+    debug_name: &'input str,
+    flow_rate: usize,
+    neighbors: Vec<&'input str>,
+}
+
+impl<'input> TryFrom<&'input str> for Valve<'input> {
+    type Error = ParseError;
+
+    fn try_from(mut input: &'input str) -> Result<Self, Self::Error> {
+        let input = &mut input;
+        let debug_name = labeled("Valve ", word).parse_next(input)?;
+        let flow_rate = labeled(" has flow rate=", integer).parse_next(input)?;
+
+        // "tunnel leads to valve" when there's only one
+        either(
+            literal("; tunnels lead to valves "),
+            literal("; tunnel leads to valve "),
+        )
+        .parse_next(input)?;
+        let neighbors = separated(word, ", ").parse_all(input)?;
+
+        Ok(Self {
+            debug_name,
+            flow_rate,
+            neighbors,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RealValve {
+    flow_rate: usize,
+    neighbors: Vec<usize>,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter::new::<String>("start"),
+        Parameter::new::<usize>("part1_minutes"),
+        Parameter::new::<usize>("part2_minutes"),
+    ];
+
+    type Parsed<'input> = (Vec<RealValve>, Distances, usize);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let valves: Vec<Valve> = input
+            .lines()
+            .map(Valve::try_from)
+            .collect::<Result<_, _>>()?;
+        if let Some(extra) = input.lines().nth(Valves::CAPACITY) {
+            return Err(ParseError::new(
+                extra,
+                format!("at most {} valves", Valves::CAPACITY),
+            ));
+        }
+        let start_name: String = config::parameter(Self::DAY, "start", "AA".into());
+        let start = valves
+            .iter()
+            .position(|valve| valve.debug_name == start_name)
+            .ok_or_else(|| {
+                ParseError::new(&input[input.len()..], format!("a valve named {start_name}"))
+            })?;
+
+        let map_of_debug_name_to_index: HashMap<&str, usize> = valves
+            .iter()
+            .enumerate()
+            .map(|(index, valve)| (valve.debug_name, index))
+            .collect();
+
+        let real_valves: Vec<RealValve> = valves
+            .into_iter()
+            .map(|old_valve| {
+                let neighbors = old_valve
+                    .neighbors
+                    .into_iter()
+                    .map(|neighbor| {
+                        map_of_debug_name_to_index
+                            .get(neighbor)
+                            .copied()
+                            .ok_or_else(|| ParseError::new(neighbor, "a valve named in the input"))
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok(RealValve {
+                    flow_rate: old_valve.flow_rate,
+                    neighbors,
+                })
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        let tunnels = |&valve: &usize| {
+            real_valves[valve]
+                .neighbors
+                .iter()
+                .map(|&neighbor| (neighbor, 1))
+        };
+        let distances = floyd_warshall(real_valves.len(), tunnels);
+        Ok((real_valves, distances, start))
+    }
+
+    fn part1((valves, distances, start): &Self::Parsed<'_>) -> Answer {
+        let minutes = config::parameter(Self::DAY, "part1_minutes", 30);
+        let max_relieved_states = solve(minutes, valves, distances, *start);
+        let part1 = *max_relieved_states.values().max().unwrap();
+        part1.into()
+    }
+
+    fn part2((valves, distances, start): &Self::Parsed<'_>) -> Option<Answer> {
+        let minutes = config::parameter(Self::DAY, "part2_minutes", 26);
+        let max_relieved_states = solve(minutes, valves, distances, *start);
+        let mut part2 = 0;
+        for (i, (elephant_opened, elephant_max)) in max_relieved_states.iter().enumerate() {
+            for (human_opened, human_max) in max_relieved_states.iter().skip(i) {
+                if !elephant_opened.is_disjoint(human_opened) {
+                    continue;
+                }
+
+                part2 = part2.max(elephant_max + human_max);
+            }
+        }
+
+        Some(part2.into())
+    }
+
+    // A random tree of tunnels from `AA` with a few shortcuts, so every valve
+    // can be reached, up to as many as `Valves` holds. Few enough have a flow
+    // to keep the search quick
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = size.clamp(2, Valves::CAPACITY);
+        let mut names: Vec<String> = ('A'..='Z')
+            .flat_map(|a| ('A'..='Z').map(move |b| format!("{a}{b}")))
+            .filter(|name| name != "AA")
+            .collect();
+        rng.shuffle(&mut names);
+        names.insert(0, "AA".to_string());
+
+        let mut tunnels = vec![vec![]; count];
+        let mut link = |a: usize, b: usize| {
+            if a != b && !tunnels[a].contains(&b) {
+                tunnels[a].push(b);
+                tunnels[b].push(a);
+            }
+        };
+        for valve in 1..count {
+            link(valve, rng.index(valve));
+        }
+        for _ in 0..count / 8 {
+            link(rng.index(count), rng.index(count));
+        }
+
+        let mut flowing: Vec<usize> = (1..count).collect();
+        rng.shuffle(&mut flowing);
+        flowing.truncate((count / 4).clamp(1, 12));
+
+        (0..count)
+            .map(|valve| {
+                let flow = if flowing.contains(&valve) {
+                    rng.range(1..=25)
+                } else {
+                    0
+                };
+                let neighbors: Vec<&str> =
+                    tunnels[valve].iter().map(|&n| names[n].as_str()).collect();
+                let lead = match neighbors.len() {
+                    1 => "tunnel leads to valve",
+                    _ => "tunnels lead to valves",
+                };
+
+                format!(
+                    "Valve {} has flow rate={flow}; {lead} {}\n",
+                    names[valve],
+                    neighbors.join(", ")
+                )
+            })
+            .collect()
+    }
+}
+
+// The valves opened so far, by index
+type Valves = BitSet<2>;
+
+struct State {
+    minutes_remaining: usize,
+    opened: Valves,
+    current_valve: usize,
+    relieved_so_far: usize,
+}
+
+fn solve(
+    minutes_remaining: usize,
+    valves: &[RealValve],
+    distances: &Distances,
+    start: usize,
+) -> HashMap<Valves, usize> {
+    let mut max_relieved_states: HashMap<Valves, usize> = HashMap::default();
+    let mut queue: VecDeque<State> = VecDeque::new();
+
+    // Opening any of the others would only waste time
+    let flowing: Valves = (0..valves.len())
+        .filter(|&valve| valves[valve].flow_rate > 0)
+        .collect();
+
+    queue.push_back(State {
+        minutes_remaining,
+        opened: Valves::new(),
+        current_valve: start,
+        relieved_so_far: 0,
+    });
+
+    while let Some(State {
+        minutes_remaining,
+        opened,
+        current_valve,
+        relieved_so_far,
+    }) = queue.pop_front()
+    {
+        // Try waiting until the end
+        let do_nothing = wait_until_end(valves, minutes_remaining, opened);
+        max_relieved_states
+            .entry(opened)
+            .and_modify(|max_relieved| {
+                *max_relieved = (*max_relieved).max(relieved_so_far + do_nothing)
+            })
+            .or_insert(relieved_so_far + do_nothing);
+
+        for valve in flowing.difference(&opened).iter() {
+            let Some(distance) = distances[current_valve][valve] else {
+                continue;
+            };
+            let how_long_to_valve = distance + 1;
+
+            if how_long_to_valve >= minutes_remaining {
+                continue;
+            }
+
+            let relieved_along_the_way = total_flowrate(valves, opened) * how_long_to_valve;
+            let relieved_so_far = relieved_so_far + relieved_along_the_way;
+
+            queue.push_back(State {
+                minutes_remaining: minutes_remaining - how_long_to_valve,
+                opened: opened.with(valve),
+                current_valve: valve,
+                relieved_so_far,
+            });
+        }
+    }
+
+    max_relieved_states
+}
+
+fn total_flowrate(valves: &[RealValve], opened: Valves) -> usize {
+    opened.iter().map(|valve| valves[valve].flow_rate).sum()
+}
+
+fn wait_until_end(valves: &[RealValve], minutes_remaining: usize, opened: Valves) -> usize {
+    minutes_remaining * total_flowrate(valves, opened)
+}
+
+// `None` between valves that aren't connected
+type Distances = Vec<Vec<Option<usize>>>;
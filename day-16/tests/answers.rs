@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_16::Day16>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,137 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_16::Day16;
+
+struct Cave {
+    flows: Vec<u32>,
+    // Steps between every pair of valves
+    distances: Vec<Vec<u32>>,
+    start: usize,
+}
+
+fn parse(input: &str) -> Cave {
+    let lines: Vec<Vec<&str>> = input
+        .lines()
+        .map(|line| {
+            line.split([' ', '=', ';', ','])
+                .filter(|word| !word.is_empty())
+                .collect()
+        })
+        .collect();
+    let index: HashMap<&str, usize> = lines
+        .iter()
+        .enumerate()
+        .map(|(i, words)| (words[1], i))
+        .collect();
+
+    let tunnels: Vec<Vec<usize>> = lines
+        .iter()
+        .map(|words| words[10..].iter().map(|name| index[name]).collect())
+        .collect();
+    let distances = (0..lines.len())
+        .map(|from| {
+            let mut distances = vec![u32::MAX; lines.len()];
+            distances[from] = 0;
+            let mut queue = VecDeque::from([from]);
+            while let Some(valve) = queue.pop_front() {
+                for &next in &tunnels[valve] {
+                    if distances[next] == u32::MAX {
+                        distances[next] = distances[valve] + 1;
+                        queue.push_back(next);
+                    }
+                }
+            }
+            distances
+        })
+        .collect();
+
+    Cave {
+        flows: lines
+            .iter()
+            .map(|words| words[5].parse().unwrap())
+            .collect(),
+        distances,
+        start: index["AA"],
+    }
+}
+
+// Tries every order of opening the valves in `allowed`, a list of flags
+fn most_pressure(cave: &Cave, at: usize, minutes: u32, allowed: &[bool]) -> u32 {
+    let mut best = 0;
+    for valve in 0..cave.flows.len() {
+        let cost = cave.distances[at][valve] + 1;
+        if allowed[valve] && cave.flows[valve] > 0 && cost < minutes {
+            let mut allowed = allowed.to_vec();
+            allowed[valve] = false;
+            let left = minutes - cost;
+            best = best.max(cave.flows[valve] * left + most_pressure(cave, valve, left, &allowed));
+        }
+    }
+
+    best
+}
+
+// Part 2 splits the flowing valves between you and the elephant every way
+// there is, rather than sharing a table of best scores
+fn naive(input: &str) -> Vec<Answer> {
+    let cave = parse(input);
+    let all = vec![true; cave.flows.len()];
+    let part1 = most_pressure(&cave, cave.start, 30, &all);
+
+    let flowing: Vec<usize> = (0..cave.flows.len())
+        .filter(|&v| cave.flows[v] > 0)
+        .collect();
+    let part2 = (0..1 << flowing.len())
+        .map(|split: u32| {
+            let mut yours = vec![false; cave.flows.len()];
+            for (i, &valve) in flowing.iter().enumerate() {
+                yours[valve] = split & 1 << i != 0;
+            }
+            let elephants: Vec<bool> = yours.iter().map(|yours| !yours).collect();
+
+            most_pressure(&cave, cave.start, 26, &yours)
+                + most_pressure(&cave, cave.start, 26, &elephants)
+        })
+        .max()
+        .unwrap();
+
+    vec![part1.into(), part2.into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day16, _>(1..=24, property::answers::<Day16>, naive);
+}
+
+// A star of 128 valves around `AA`, more than fit in a single word of the set
+// of opened valves, with the only flows past the first word
+#[test]
+fn agrees_with_naive_beyond_64_valves() {
+    let name = |i: usize| {
+        let letter = |n: usize| char::from(b'A' + n as u8);
+        format!("{}{}", letter(i / 26), letter(i % 26))
+    };
+    let flow = |i: usize| match i {
+        70 => 10,
+        100 => 20,
+        127 => 30,
+        _ => 0,
+    };
+
+    let others: Vec<String> = (1..128).map(name).collect();
+    let mut input = format!(
+        "Valve AA has flow rate=0; tunnels lead to valves {}\n",
+        others.join(", ")
+    );
+    for i in 1..128 {
+        input += &format!(
+            "Valve {} has flow rate={}; tunnel leads to valve AA\n",
+            name(i),
+            flow(i)
+        );
+    }
+
+    assert_eq!(property::answers::<Day16>(&input), naive(&input));
+}
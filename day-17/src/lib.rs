@@ -0,0 +1,398 @@
+use aoc_common::animate;
+use aoc_common::config::{self, Parameter};
+use aoc_common::cycle::CycleDetector;
+use aoc_common::generate::Rng;
+use aoc_common::geometry::{Direction, Point2};
+use aoc_common::grid::Grid;
+use aoc_common::image::{self, Image, Rgb};
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::trace;
+
+use Direction::*;
+use PieceKind::*;
+use Tile::*;
+
+const WIDTH: usize = 7;
+
+// The order the pieces fall in, over and over
+const PIECE_KINDS: [PieceKind; 5] = [Line, Plus, Ell, Column, Square];
+
+fn jet(c: char) -> Option<Direction> {
+    match c {
+        '<' => Some(Left),
+        '>' => Some(Right),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum PieceKind {
+    Line,
+    Plus,
+    Ell,
+    Column,
+    Square,
+}
+impl PieceKind {
+    fn height(&self) -> usize {
+        match self {
+            Line => 1,
+            Plus => 3,
+            Ell => 3,
+            Column => 4,
+            Square => 2,
+        }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    #[default]
+    Air,
+    Rock,
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Air => '.',
+            Rock => '#',
+        }
+    }
+}
+
+// The tower grows from row 0 upwards, so a falling piece moves towards
+// smaller `y`
+type Point = Point2<usize>;
+
+const FALL: (isize, isize) = (-1, 0);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Piece {
+    kind: PieceKind,
+    position: Point,
+}
+
+impl Piece {
+    fn new(kind: PieceKind, top: usize) -> Piece {
+        let y = top + 2 + kind.height();
+
+        Piece {
+            kind,
+            position: Point::new(y, 2),
+        }
+    }
+
+    fn descend(&mut self, tower: &Tower) -> Option<()> {
+        let would_be = Self {
+            position: self.position.checked_offset(FALL)?,
+            ..*self
+        };
+
+        if !would_be.legal() {
+            return None;
+        }
+
+        if tower.collides(would_be) {
+            None
+        } else {
+            *self = would_be;
+            Some(())
+        }
+    }
+
+    fn shift(&mut self, direction: Direction, tower: &Tower) -> Option<()> {
+        let new_position = self.position.checked_step(direction)?;
+
+        let would_be = Self {
+            position: new_position,
+            ..*self
+        };
+
+        if !would_be.legal() {
+            return None;
+        }
+
+        if tower.collides(would_be) {
+            None
+        } else {
+            *self = would_be;
+            Some(())
+        }
+    }
+
+    // The piece's position is the top left corner of its bounding box
+    fn points(&self) -> Option<Vec<Point>> {
+        let shape: &[(isize, isize)] = match self.kind {
+            Line => &[(0, 0), (0, 1), (0, 2), (0, 3)],
+            Plus => &[(0, 1), (-1, 0), (-1, 1), (-1, 2), (-2, 1)],
+            Ell => &[(0, 2), (-1, 2), (-2, 0), (-2, 1), (-2, 2)],
+            Column => &[(0, 0), (-1, 0), (-2, 0), (-3, 0)],
+            Square => &[(0, 0), (0, 1), (-1, 0), (-1, 1)],
+        };
+
+        shape
+            .iter()
+            .map(|&offset| {
+                self.position
+                    .checked_offset(offset)
+                    .filter(|point| point.x < WIDTH)
+            })
+            .collect()
+    }
+
+    pub fn legal(&self) -> bool {
+        self.points().is_some()
+    }
+}
+
+type Row = [Tile; WIDTH];
+
+struct Tower {
+    grid: Vec<Row>,
+    top: usize,
+}
+
+impl Tower {
+    fn new() -> Self {
+        Self {
+            grid: vec![[Air; WIDTH]; 7],
+            top: 0,
+        }
+    }
+
+    fn recalculate_top(&mut self) {
+        let old_top = self.top;
+        self.top = self.find_top(old_top);
+    }
+
+    fn find_top(&self, hint: usize) -> usize {
+        self.grid
+            .iter()
+            .enumerate()
+            .skip(hint)
+            .find(|(_i, row)| row.iter().all(|tile| *tile == Air))
+            .map(|(i, _row)| i)
+            .unwrap()
+    }
+
+    fn draw_piece(&mut self, piece: Piece) {
+        for point in piece.points().unwrap() {
+            self.grid[point.y][point.x] = Rock;
+        }
+    }
+
+    fn erase_piece(&mut self, piece: Piece) {
+        for point in piece.points().unwrap() {
+            self.grid[point.y][point.x] = Air;
+        }
+    }
+
+    fn lock_in_piece(&mut self, piece: Piece) {
+        self.draw_piece(piece);
+
+        // TODO: inefficient
+        for _ in 0..piece.kind.height() {
+            self.grid.push(Default::default());
+        }
+    }
+
+    // How far below the top each column's highest rock is, which is all
+    // that decides where later pieces come to rest
+    fn surface(&self) -> [usize; WIDTH] {
+        std::array::from_fn(|x| {
+            (0..self.top)
+                .rev()
+                .find(|&y| self.grid[y][x] == Rock)
+                .map_or(self.top, |y| self.top - y)
+        })
+    }
+
+    // `rows` rows of the tower going down from just below `top`, and the
+    // floor if it's that short
+    fn draw(&self, top: usize, rows: usize) -> String {
+        let mut drawing = String::new();
+        for row in self.grid[..top].iter().rev().take(rows) {
+            let row: String = row.iter().copied().map(char::from).collect();
+            drawing += &format!("|{row}|\n");
+        }
+
+        if top <= rows {
+            drawing += "+-------+\n";
+        }
+
+        drawing
+    }
+
+    // The whole tower, top first
+    fn paint(&self) -> Image {
+        let rows = Grid::from_fn(self.top, WIDTH, |(y, x)| self.grid[self.top - 1 - y][x]);
+        Image::from_grid(&rows, |tile| match tile {
+            Air => Rgb(20, 20, 35),
+            Rock => Rgb(170, 150, 130),
+        })
+    }
+
+    fn lookup(&self, point: Point) -> Tile {
+        self.grid[point.y][point.x]
+    }
+
+    fn collides(&self, piece: Piece) -> bool {
+        let Some(points) = piece.points() else {
+            return false;
+        };
+
+        for point in points {
+            if self.lookup(point) == Rock {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    #[allow(unused)]
+    pub fn top_is_floor(&self) -> bool {
+        self.grid[self.top].into_iter().all(|tile| tile == Rock)
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter::new::<usize>("part1_rocks"),
+        Parameter::new::<usize>("part2_rocks"),
+    ];
+
+    type Parsed<'input> = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let input = input.trim_end();
+        if input.is_empty() {
+            return Err(ParseError::new(input, "a jet (`<` or `>`)"));
+        }
+
+        input
+            .char_indices()
+            .map(|(i, c)| jet(c).ok_or_else(|| ParseError::new(&input[i..], "a jet (`<` or `>`)")))
+            .collect()
+    }
+
+    fn part1(jets: &Self::Parsed<'_>) -> Answer {
+        let rocks = config::parameter(Self::DAY, "part1_rocks", 2022);
+        let mut tower = Tower::new();
+        let mut jet = 0;
+
+        for rock in 0..rocks {
+            drop_rock(
+                &mut tower,
+                PIECE_KINDS[rock % PIECE_KINDS.len()],
+                jets,
+                &mut jet,
+            );
+        }
+
+        trace!(Part, "tower", "top 20 rows\n{}", tower.draw(tower.top, 20));
+        image::export(Self::DAY, "tower", || tower.paint());
+        tower.top.into()
+    }
+
+    fn part2(jets: &Self::Parsed<'_>) -> Option<Answer> {
+        let mut tower = Tower::new();
+        let mut jet = 0;
+        let mut detector = CycleDetector::new();
+
+        // The tower's height before each rock, until the next rock, jet and
+        // surface come round again
+        let cycle = (0..)
+            .find_map(|rock| {
+                let piece_kind = rock % PIECE_KINDS.len();
+                let cycle = detector.push((piece_kind, jet, tower.surface()), tower.top);
+                drop_rock(&mut tower, PIECE_KINDS[piece_kind], jets, &mut jet);
+                cycle
+            })
+            .unwrap();
+
+        trace!(
+            Part,
+            "cycle",
+            "rocks {}.. repeat every {} rocks, adding {} rows",
+            cycle.start,
+            cycle.period,
+            cycle.delta()
+        );
+        let rocks = config::parameter(Self::DAY, "part2_rocks", 1_000_000_000_000);
+        Some(cycle.value_at(rocks).into())
+    }
+
+    // Some jet patterns never push a rock into one of the columns, which then
+    // gets deeper forever and keeps part 2 from finding a cycle, so those are
+    // thrown away
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        loop {
+            let input: String = (0..(size * 10).max(100))
+                .map(|_| *rng.pick(&['<', '>']))
+                .chain(['\n'])
+                .collect();
+            let jets = Self::parse(&input).unwrap();
+
+            let mut tower = Tower::new();
+            let mut jet = 0;
+            for rock in 0..2022 {
+                drop_rock(
+                    &mut tower,
+                    PIECE_KINDS[rock % PIECE_KINDS.len()],
+                    &jets,
+                    &mut jet,
+                );
+            }
+
+            if tower.surface().iter().all(|&depth| depth < 100) {
+                return input;
+            }
+        }
+    }
+}
+
+fn drop_rock(tower: &mut Tower, piece_kind: PieceKind, jets: &[Direction], jet: &mut usize) {
+    let mut piece = Piece::new(piece_kind, tower.top);
+
+    // Until piece rests
+    loop {
+        let jet_direction = jets[*jet];
+        *jet = (*jet + 1) % jets.len();
+
+        let shifted = piece.shift(jet_direction, tower);
+        let descended = piece.descend(tower);
+
+        if animate::enabled() {
+            tower.draw_piece(piece);
+            animate!("{}", tower.draw(piece.position.y + 1, 30));
+            tower.erase_piece(piece);
+        }
+        trace!(
+            Step,
+            "jet",
+            "{piece_kind:?} pushed {jet_direction:?}{}, now at {}",
+            if shifted.is_some() { "" } else { " (blocked)" },
+            piece.position
+        );
+
+        if descended.is_none() {
+            break;
+        }
+    }
+
+    tower.lock_in_piece(piece);
+    tower.recalculate_top();
+    trace!(
+        Round,
+        "rock",
+        "{piece_kind:?} came to rest at {}, top at {}",
+        piece.position,
+        tower.top
+    );
+}
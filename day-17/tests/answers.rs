@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_17::Day17>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_17::Day17;
+
+const ROCKS: [&[(i64, i64)]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+// Keeps every settled cell in a set, with `y` going up from the floor. Part 2
+// drops far too many rocks to check this way, so only part 1 is compared
+fn naive(input: &str) -> Vec<Answer> {
+    let jets = input.trim_end().as_bytes();
+    let mut settled: HashSet<(i64, i64)> = HashSet::new();
+    let mut top = 0;
+    let mut jet = 0;
+
+    for rock in ROCKS.iter().cycle().take(2022) {
+        let fits = |(x, y): (i64, i64)| {
+            rock.iter().all(|&(dx, dy)| {
+                (0..7).contains(&(x + dx)) && y + dy >= 0 && !settled.contains(&(x + dx, y + dy))
+            })
+        };
+
+        let (mut x, mut y) = (2, top + 3);
+        loop {
+            let pushed = if jets[jet] == b'<' { x - 1 } else { x + 1 };
+            jet = (jet + 1) % jets.len();
+            if fits((pushed, y)) {
+                x = pushed;
+            }
+
+            if !fits((x, y - 1)) {
+                break;
+            }
+            y -= 1;
+        }
+
+        for &(dx, dy) in rock.iter() {
+            settled.insert((x + dx, y + dy));
+            top = top.max(y + dy + 1);
+        }
+    }
+
+    vec![top.into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    let part1 = |input: &str| property::answers::<Day17>(input)[..1].to_vec();
+    property::agree::<Day17, _>(1..=5, part1, naive);
+}
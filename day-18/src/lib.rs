@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use aoc_common::bitset::GrowableBitSet;
+use aoc_common::generate::Rng;
+use aoc_common::geometry::{Point2, Point3};
+use aoc_common::image::{self, Image, Rgb};
+use aoc_common::parse::{integer, labeled, ParseError, Parser};
+use aoc_common::search::bfs;
+use aoc_common::solution::{Answer, Solution};
+
+pub type Point = Point3<isize>;
+
+fn parse_point(mut line: &str) -> Result<Point, ParseError> {
+    let input = &mut line;
+    let z = integer(input)?;
+    let y = labeled(",", integer).parse_next(input)?;
+    let x = labeled(",", integer).parse_all(input)?;
+
+    Ok(Point { z, y, x })
+}
+
+fn coordinates(point: Point) -> [isize; 3] {
+    [point.z, point.y, point.x]
+}
+
+// The space the solution looks at along each axis: wherever the cubes are,
+// with a layer of air all round that the air outside them flows through from
+// one side to another
+fn space(points: &HashSet<Point>) -> RangeInclusive<isize> {
+    let mut coordinates = points.iter().flat_map(|point| coordinates(*point));
+    let first = coordinates.next().unwrap_or_default();
+    let (min, max) = coordinates.fold((first, first), |(min, max), coordinate| {
+        (min.min(coordinate), max.max(coordinate))
+    });
+    min - 1..=max + 1
+}
+
+fn escaped(space: &RangeInclusive<isize>, point: Point) -> bool {
+    coordinates(point)
+        .into_iter()
+        .any(|coordinate| !space.contains(&coordinate))
+}
+
+// Where a point in the space is in a set of them
+fn index(space: &RangeInclusive<isize>, point: Point) -> usize {
+    let side = (space.end() - space.start() + 1) as usize;
+    coordinates(point).into_iter().fold(0, |index, coordinate| {
+        index * side + (coordinate - space.start()) as usize
+    })
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Parsed<'input> = HashSet<Point>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(parse_point).collect()
+    }
+
+    fn part1(points: &Self::Parsed<'_>) -> Answer {
+        let space = space(points);
+        let mut rock = GrowableBitSet::new();
+
+        let mut surface_area = 0;
+        for point in points {
+            rock.insert(index(&space, *point));
+            surface_area += 6;
+
+            for neighbor in point.neighbors6() {
+                if rock.contains(index(&space, neighbor)) {
+                    surface_area -= 2;
+                }
+            }
+        }
+
+        surface_area.into()
+    }
+
+    fn part2(points: &Self::Parsed<'_>) -> Option<Answer> {
+        let space = space(points);
+        let air = |point: &Point| {
+            point
+                .neighbors6()
+                .filter(|neighbor| !escaped(&space, *neighbor) && !points.contains(neighbor))
+                .collect::<Vec<_>>()
+        };
+        let corner = *space.end();
+        let outside = bfs(Point::new(corner, corner, corner), air, |_| false);
+        image::export(Self::DAY, "slices", || {
+            paint_slices(points, |point| outside.reached(point))
+        });
+
+        let mut surface_area = 0;
+        for point in points {
+            for neighbor in point.neighbors6() {
+                if outside.reached(&neighbor) {
+                    surface_area += 1;
+                }
+            }
+        }
+
+        Some(surface_area.into())
+    }
+
+    // A blob grown one cube at a time next to the ones before
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = (size * 10).min(4_000);
+        let mut cubes = vec![Point {
+            z: 10,
+            y: 10,
+            x: 10,
+        }];
+        let mut seen: HashSet<Point> = cubes.iter().copied().collect();
+
+        while cubes.len() < count {
+            let neighbors: Vec<Point> = rng
+                .pick(&cubes)
+                .neighbors6()
+                .filter(|cube| [cube.z, cube.y, cube.x].iter().all(|c| (0..20).contains(c)))
+                .collect();
+            let cube = *rng.pick(&neighbors);
+            if seen.insert(cube) {
+                cubes.push(cube);
+            }
+        }
+
+        cubes
+            .iter()
+            .map(|cube| format!("{},{},{}\n", cube.z, cube.y, cube.x))
+            .collect()
+    }
+}
+
+// Every slice through the droplet from front to back, side by side, with lava
+// in orange, the air outside in blue and air pockets in yellow
+fn paint_slices(points: &HashSet<Point>, outside: impl Fn(&Point) -> bool) -> Image {
+    let range = |axis: fn(&Point) -> isize| {
+        let min = points.iter().map(axis).min().unwrap_or_default();
+        let max = points.iter().map(axis).max().unwrap_or_default();
+        min..=max
+    };
+    let (zs, ys, xs) = (range(|p| p.z), range(|p| p.y), range(|p| p.x));
+    // Slices are a column apart
+    let stride = xs.end() - xs.start() + 2;
+
+    let mut pixels = vec![];
+    for z in zs.clone() {
+        for y in ys.clone() {
+            for x in xs.clone() {
+                let point = Point::new(z, y, x);
+                let color = if points.contains(&point) {
+                    Rgb(240, 110, 30)
+                } else if outside(&point) {
+                    Rgb(30, 50, 90)
+                } else {
+                    Rgb(240, 220, 60)
+                };
+
+                let column = (z - zs.start()) * stride + x - xs.start();
+                pixels.push((Point2::new(y - ys.start(), column), color));
+            }
+        }
+    }
+
+    Image::from_points(pixels, Rgb::BLACK)
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_18::Day18>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_18::Day18;
+
+const SIDES: [(i32, i32, i32); 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+fn next_to((x, y, z): (i32, i32, i32)) -> impl Iterator<Item = (i32, i32, i32)> {
+    SIDES
+        .into_iter()
+        .map(move |(dx, dy, dz)| (x + dx, y + dy, z + dz))
+}
+
+// Counts each cube's open sides, then floods the air around the droplet
+// within a box one bigger than it
+fn naive(input: &str) -> Vec<Answer> {
+    let cubes: HashSet<(i32, i32, i32)> = input
+        .lines()
+        .map(|line| {
+            let coordinates: Vec<i32> = line.split(',').map(|c| c.parse().unwrap()).collect();
+            (coordinates[0], coordinates[1], coordinates[2])
+        })
+        .collect();
+
+    let open = cubes
+        .iter()
+        .flat_map(|&cube| next_to(cube))
+        .filter(|side| !cubes.contains(side))
+        .count();
+
+    let low = cubes.iter().flat_map(|&(x, y, z)| [x, y, z]).min().unwrap() - 1;
+    let high = cubes.iter().flat_map(|&(x, y, z)| [x, y, z]).max().unwrap() + 1;
+    let mut outside = HashSet::from([(low, low, low)]);
+    let mut stack = vec![(low, low, low)];
+    let mut exterior = 0;
+    while let Some(air) = stack.pop() {
+        for next in next_to(air) {
+            if cubes.contains(&next) {
+                exterior += 1;
+            } else if [next.0, next.1, next.2]
+                .iter()
+                .all(|c| (low..=high).contains(c))
+                && outside.insert(next)
+            {
+                stack.push(next);
+            }
+        }
+    }
+
+    vec![open.into(), exterior.into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day18, _>(1..=5, property::answers::<Day18>, naive);
+}
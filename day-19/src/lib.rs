@@ -0,0 +1,298 @@
+use std::collections::BinaryHeap;
+use std::str::FromStr;
+
+use aoc_common::config::{self, Parameter};
+use aoc_common::generate::Rng;
+use aoc_common::parse::{integer, labeled, literal, ParseError, Parser};
+use aoc_common::pool;
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Blueprint {
+    number: u8,
+    ore_robot_cost: u8,
+    clay_robot_cost: u8,
+    obsidian_robot_ore_cost: u8,
+    obsidian_robot_clay_cost: u8,
+    geode_robot_ore_cost: u8,
+    geode_robot_obsidian_cost: u8,
+}
+
+impl FromStr for Blueprint {
+    type Err = ParseError;
+
+    fn from_str(mut input: &str) -> Result<Self, Self::Err> {
+        let input = &mut input;
+        let blueprint = Self {
+            number: labeled("Blueprint ", integer).parse_next(input)?,
+            ore_robot_cost: labeled(": Each ore robot costs ", integer).parse_next(input)?,
+            clay_robot_cost: labeled(" ore. Each clay robot costs ", integer).parse_next(input)?,
+            obsidian_robot_ore_cost: labeled(" ore. Each obsidian robot costs ", integer)
+                .parse_next(input)?,
+            obsidian_robot_clay_cost: labeled(" ore and ", integer).parse_next(input)?,
+            geode_robot_ore_cost: labeled(" clay. Each geode robot costs ", integer)
+                .parse_next(input)?,
+            geode_robot_obsidian_cost: labeled(" ore and ", integer).parse_next(input)?,
+        };
+        literal(" obsidian.").parse_all(input)?;
+
+        Ok(blueprint)
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter::new::<u8>("part1_minutes"),
+        Parameter::new::<u8>("part2_minutes"),
+        Parameter::new::<usize>("part2_blueprints"),
+    ];
+
+    type Parsed<'input> = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(blueprints: &Self::Parsed<'_>) -> Answer {
+        let minutes = config::parameter(Self::DAY, "part1_minutes", 24);
+        let geodes = pool::map(blueprints, |&blueprint| test_blueprint(blueprint, minutes));
+
+        let part1: u64 = blueprints
+            .iter()
+            .zip(geodes)
+            .map(|(blueprint, geodes)| u64::from(blueprint.number) * u64::from(geodes))
+            .sum();
+        part1.into()
+    }
+
+    fn part2(blueprints: &Self::Parsed<'_>) -> Option<Answer> {
+        let minutes = config::parameter(Self::DAY, "part2_minutes", 32);
+        let count = config::parameter(Self::DAY, "part2_blueprints", 3);
+        let geodes = pool::map(blueprints.iter().take(count), |&blueprint| {
+            test_blueprint(blueprint, minutes)
+        });
+
+        // A few blueprints' geodes can outgrow a u16 between them
+        let part2: u64 = geodes.into_iter().map(u64::from).product();
+        Some(part2.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (1..=size)
+            .map(|id| {
+                let mut cost = |range| rng.range(range);
+                format!(
+                    "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                    cost(2..=4),
+                    cost(2..=4),
+                    cost(2..=4),
+                    cost(5..=20),
+                    cost(2..=4),
+                    cost(5..=20),
+                )
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct State {
+    minutes_elapsed: u8,
+    ore_robots: u8,
+    clay_robots: u8,
+    obsidian_robots: u8,
+    geode_robots: u8,
+    ore: u8,
+    clay: u8,
+    obsidian: u8,
+    geodes: u8,
+    can_build_ore: bool,
+    can_build_clay: bool,
+    can_build_obsidian: bool,
+    can_build_geode: bool,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            minutes_elapsed: 0,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geodes: 0,
+            can_build_ore: true,
+            can_build_clay: true,
+            can_build_obsidian: true,
+            can_build_geode: true,
+        }
+    }
+}
+
+impl State {
+    fn next(self) -> Self {
+        Self {
+            minutes_elapsed: self.minutes_elapsed + 1,
+            ore: self.ore + self.ore_robots,
+            clay: self.clay + self.clay_robots,
+            obsidian: self.obsidian + self.obsidian_robots,
+            geodes: self.geodes + self.geode_robots,
+            ..self
+        }
+    }
+
+    fn build_ore_robot(self, blueprint: Blueprint) -> Self {
+        Self {
+            ore: self.ore + self.ore_robots - blueprint.ore_robot_cost,
+            ore_robots: self.ore_robots + 1,
+            can_build_ore: true,
+            can_build_clay: true,
+            can_build_obsidian: true,
+            can_build_geode: true,
+            ..self.next()
+        }
+    }
+
+    fn build_clay_robot(self, blueprint: Blueprint) -> Self {
+        Self {
+            ore: self.ore + self.ore_robots - blueprint.clay_robot_cost,
+            clay_robots: self.clay_robots + 1,
+            can_build_ore: true,
+            can_build_clay: true,
+            can_build_obsidian: true,
+            can_build_geode: true,
+            ..self.next()
+        }
+    }
+
+    fn build_obsidian_robot(self, blueprint: Blueprint) -> Self {
+        Self {
+            ore: self.ore + self.ore_robots - blueprint.obsidian_robot_ore_cost,
+            clay: self.clay + self.clay_robots - blueprint.obsidian_robot_clay_cost,
+            obsidian_robots: self.obsidian_robots + 1,
+            can_build_ore: true,
+            can_build_clay: true,
+            can_build_obsidian: true,
+            can_build_geode: true,
+            ..self.next()
+        }
+    }
+
+    fn build_geode_robot(self, blueprint: Blueprint) -> Self {
+        Self {
+            ore: self.ore + self.ore_robots - blueprint.geode_robot_ore_cost,
+            obsidian: self.obsidian + self.obsidian_robots - blueprint.geode_robot_obsidian_cost,
+            geode_robots: self.geode_robots + 1,
+            can_build_ore: true,
+            can_build_clay: true,
+            can_build_obsidian: true,
+            can_build_geode: true,
+            ..self.next()
+        }
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.geodes.cmp(&other.geodes)
+    }
+}
+
+fn test_blueprint(blueprint: Blueprint, max_minutes: u8) -> u16 {
+    let mut heap = BinaryHeap::new();
+    heap.push(State::default());
+    let mut most_geodes: u16 = 0;
+
+    while let Some(mut state) = heap.pop() {
+        most_geodes = most_geodes.max(state.geodes as u16);
+
+        if state.minutes_elapsed == max_minutes {
+            continue;
+        }
+
+        // If there's no way to beat the best score, just give up
+        if maximum_possible_geodes(state, max_minutes) <= most_geodes {
+            continue;
+        }
+
+        // Possible decisions:
+        if state.can_build_ore
+            && state.ore >= blueprint.geode_robot_ore_cost
+            && state.obsidian >= blueprint.geode_robot_obsidian_cost
+        {
+            heap.push(state.build_geode_robot(blueprint));
+            state.can_build_ore = false;
+        }
+
+        if state.can_build_clay
+            && !dont_need_to_build_obsidian_robot(state, blueprint)
+            && state.ore >= blueprint.obsidian_robot_ore_cost
+            && state.clay >= blueprint.obsidian_robot_clay_cost
+        {
+            heap.push(state.build_obsidian_robot(blueprint));
+            state.can_build_clay = false;
+        }
+
+        if state.can_build_obsidian
+            && !dont_need_to_build_clay_robot(state, blueprint)
+            && state.ore >= blueprint.clay_robot_cost
+        {
+            heap.push(state.build_clay_robot(blueprint));
+            state.can_build_obsidian = false;
+        }
+
+        if state.can_build_geode
+            && !dont_need_to_build_ore_robot(state, blueprint)
+            && state.ore >= blueprint.ore_robot_cost
+        {
+            heap.push(state.build_ore_robot(blueprint));
+            state.can_build_geode = false;
+        }
+
+        heap.push(state.next());
+    }
+
+    most_geodes
+}
+
+// Returns true if we never need to build another ore robot again
+// (e.g., if we're already producing enough ore)
+fn dont_need_to_build_ore_robot(state: State, blueprint: Blueprint) -> bool {
+    let max_ore_cost = blueprint
+        .ore_robot_cost
+        .max(blueprint.clay_robot_cost)
+        .max(blueprint.obsidian_robot_ore_cost)
+        .max(blueprint.geode_robot_ore_cost);
+
+    state.ore_robots >= max_ore_cost
+}
+
+fn dont_need_to_build_clay_robot(state: State, blueprint: Blueprint) -> bool {
+    state.clay_robots >= blueprint.obsidian_robot_clay_cost
+}
+
+fn dont_need_to_build_obsidian_robot(state: State, blueprint: Blueprint) -> bool {
+    state.obsidian_robots >= blueprint.geode_robot_obsidian_cost
+}
+
+fn maximum_possible_geodes(mut state: State, max_minutes: u8) -> u16 {
+    let mut max: u16 = state.geodes as u16;
+    for _ in state.minutes_elapsed..max_minutes {
+        max += state.geode_robots as u16;
+        state.geode_robots += 1;
+    }
+
+    max
+}
@@ -0,0 +1,91 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_19::Day19;
+
+// What each of the ore, clay, obsidian and geode robots costs in each of
+// those
+type Costs = [[u32; 4]; 4];
+
+fn parse(line: &str) -> (u32, Costs) {
+    let numbers: Vec<u32> = line
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|n| !n.is_empty())
+        .map(|n| n.parse().unwrap())
+        .collect();
+    let [id, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] = numbers[..]
+    else {
+        panic!("not a blueprint: {line:?}");
+    };
+
+    let costs = [
+        [ore, 0, 0, 0],
+        [clay, 0, 0, 0],
+        [obsidian_ore, obsidian_clay, 0, 0],
+        [geode_ore, 0, geode_obsidian, 0],
+    ];
+    (id, costs)
+}
+
+// Picks which robot to save up for next, depth first, giving up on branches
+// that couldn't beat the best so far even building a geode robot every
+// minute
+fn most_geodes(costs: &Costs, minutes: u32, robots: [u32; 4], stock: [u32; 4], best: &mut u32) {
+    let idle = stock[3] + robots[3] * minutes;
+    *best = (*best).max(idle);
+    if idle + minutes * minutes.saturating_sub(1) / 2 <= *best {
+        return;
+    }
+
+    for robot in 0..4 {
+        let most_needed = costs.iter().map(|cost| cost[robot]).max().unwrap();
+        if robot < 3 && robots[robot] >= most_needed {
+            continue;
+        }
+
+        let wait = (0..3)
+            .filter(|&resource| costs[robot][resource] > stock[resource])
+            .map(|resource| {
+                let missing = costs[robot][resource] - stock[resource];
+                (robots[resource] > 0).then(|| missing.div_ceil(robots[resource]))
+            })
+            .try_fold(0, |wait, needed| Some(wait.max(needed?)));
+
+        if let Some(wait) = wait.filter(|&wait| wait + 1 < minutes) {
+            let mut next_stock = stock;
+            for resource in 0..4 {
+                next_stock[resource] += robots[resource] * (wait + 1);
+                next_stock[resource] -= costs[robot][resource];
+            }
+            let mut next_robots = robots;
+            next_robots[robot] += 1;
+            most_geodes(costs, minutes - wait - 1, next_robots, next_stock, best);
+        }
+    }
+}
+
+fn geodes(costs: &Costs, minutes: u32) -> u32 {
+    let mut best = 0;
+    most_geodes(costs, minutes, [1, 0, 0, 0], [0; 4], &mut best);
+    best
+}
+
+fn naive(input: &str) -> Vec<Answer> {
+    let blueprints: Vec<(u32, Costs)> = input.lines().map(parse).collect();
+    let quality: u32 = blueprints
+        .iter()
+        .map(|(id, costs)| id * geodes(costs, 24))
+        .sum();
+    let first_three: u32 = blueprints
+        .iter()
+        .take(3)
+        .map(|(_, costs)| geodes(costs, 32))
+        .product();
+
+    vec![quality.into(), first_three.into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    // Three blueprints, which is all part 2 looks at
+    property::agree::<Day19, _>(3..=3, property::answers::<Day19>, naive);
+}
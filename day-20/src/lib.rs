@@ -0,0 +1,90 @@
+use aoc_common::config::{self, Parameter};
+use aoc_common::generate::Rng;
+use aoc_common::numeric;
+use aoc_common::parse::{number, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter::new::<i64>("key"),
+        Parameter::new::<usize>("part2_mixes"),
+        Parameter::list::<usize>("offsets"),
+    ];
+
+    type Parsed<'input> = Vec<(usize, i64)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let numbers: Vec<i64> = input.lines().map(number).collect::<Result<_, _>>()?;
+        Ok(numbers.into_iter().enumerate().collect())
+    }
+
+    fn part1(numbers: &Self::Parsed<'_>) -> Answer {
+        let part1 = decrypt(numbers.clone(), 1, &offsets());
+        part1.into()
+    }
+
+    fn part2(numbers: &Self::Parsed<'_>) -> Option<Answer> {
+        let key: i64 = config::parameter(Self::DAY, "key", 811_589_153);
+        let mixes = config::parameter(Self::DAY, "part2_mixes", 10);
+
+        let mut numbers = numbers.clone();
+        numbers.iter_mut().for_each(|(_i, num)| *num *= key);
+        let part2 = decrypt(numbers, mixes, &offsets());
+        Some(part2.into())
+    }
+
+    // Exactly one zero, which the grove coordinates are counted from
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = size.max(2);
+        let zero = rng.index(count);
+
+        (0..count)
+            .map(|i| {
+                let number = match rng.range(-9_999..=9_998) {
+                    _ if i == zero => 0,
+                    n if n >= 0 => n + 1,
+                    n => n,
+                };
+                format!("{number}\n")
+            })
+            .collect()
+    }
+}
+
+// How far after the zero the grove coordinates are
+fn offsets() -> Vec<usize> {
+    config::list(Day20::DAY, "offsets", vec![1_000, 2_000, 3_000])
+}
+
+fn decrypt(mut numbers: Vec<(usize, i64)>, cycles: usize, offsets: &[usize]) -> i64 {
+    for _ in 0..cycles {
+        mix(&mut numbers);
+    }
+
+    let zero = numbers.iter().position(|(_, number)| *number == 0).unwrap();
+    offsets
+        .iter()
+        .map(|offset| numbers[(zero + offset) % numbers.len()].1)
+        .sum()
+}
+
+// A number taken out of the circle moves around the other `length - 1`, so
+// where it goes back in is its old place plus itself, wrapped around those
+fn mix(numbers: &mut [(usize, i64)]) {
+    let length = numbers.len();
+    for index in 0..length {
+        let from = numbers.iter().position(|(i, _)| *i == index).unwrap();
+        let number = numbers[from].1;
+        let to = numeric::wrap(from as i64 + number, length as i64 - 1) as usize;
+
+        if to > from {
+            numbers.copy_within(from + 1..=to, from);
+        } else {
+            numbers.copy_within(to..from, to + 1);
+        }
+        numbers[to] = (index, number);
+    }
+}
@@ -0,0 +1,40 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_20::Day20;
+
+// Finds, takes out and puts back each number in a plain list, tagged with
+// where it started
+fn grove_coordinates(numbers: &[i64], rounds: usize) -> i64 {
+    let mut list: Vec<(usize, i64)> = numbers.iter().copied().enumerate().collect();
+    let others = numbers.len() as i64 - 1;
+
+    for _ in 0..rounds {
+        for start in 0..numbers.len() {
+            let at = list.iter().position(|&(i, _)| i == start).unwrap();
+            let entry = list.remove(at);
+            let to = (at as i64 + entry.1).rem_euclid(others) as usize;
+            list.insert(to, entry);
+        }
+    }
+
+    let zero = list.iter().position(|&(_, number)| number == 0).unwrap();
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| list[(zero + offset) % list.len()].1)
+        .sum()
+}
+
+fn naive(input: &str) -> Vec<Answer> {
+    let numbers: Vec<i64> = input.lines().map(|line| line.parse().unwrap()).collect();
+    let decrypted: Vec<i64> = numbers.iter().map(|number| number * 811_589_153).collect();
+
+    vec![
+        grove_coordinates(&numbers, 1).into(),
+        grove_coordinates(&decrypted, 10).into(),
+    ]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day20, _>(1..=40, property::answers::<Day20>, naive);
+}
@@ -0,0 +1,390 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_common::generate::Rng;
+use aoc_common::parse::{end, integer, labeled, literal, word, ParseError, Parser};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Debug)]
+pub enum Monkey<'a> {
+    Number(u64),
+    Math {
+        operation: Operation,
+        dependents: (&'a str, &'a str),
+    },
+    Human(u64),
+}
+
+impl<'a> TryFrom<&'a str> for Monkey<'a> {
+    type Error = ParseError;
+
+    fn try_from(mut value: &'a str) -> Result<Self, Self::Error> {
+        let input = &mut value;
+        if input.starts_with(|c: char| c.is_ascii_digit()) {
+            let number = integer(input)?;
+            end(input)?;
+            return Ok(Self::Number(number));
+        }
+
+        let a = word(input)?;
+        let operation = labeled(" ", operation).parse_next(input)?;
+        let b = labeled(" ", word).parse_all(input)?;
+
+        Ok(Self::Math {
+            operation,
+            dependents: (a, b),
+        })
+    }
+}
+
+// A single character
+fn operation(input: &mut &str) -> Result<Operation, ParseError> {
+    let (symbol, rest) = input.split_at(input.chars().next().map_or(0, char::len_utf8));
+    let operation = symbol.parse()?;
+    *input = rest;
+    Ok(operation)
+}
+
+fn parse_monkey(mut line: &str) -> Result<(&str, Monkey<'_>), ParseError> {
+    let input = &mut line;
+    let name = word(input)?;
+    literal(": ").parse_next(input)?;
+
+    if name == "humn" {
+        let number = integer(input)?;
+        end(input)?;
+        Ok((name, Monkey::Human(number)))
+    } else {
+        Ok((name, Monkey::try_from(*input)?))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "+" => Ok(Self::Add),
+            "-" => Ok(Self::Subtract),
+            "*" => Ok(Self::Multiply),
+            "/" => Ok(Self::Divide),
+            _ => Err(ParseError::new(
+                value,
+                "an operation (`+`, `-`, `*` or `/`)",
+            )),
+        }
+    }
+}
+
+impl Operation {
+    fn apply(self, a: u64, b: u64) -> u64 {
+        match self {
+            Operation::Add => a + b,
+            Operation::Subtract => a - b,
+            Operation::Multiply => a * b,
+            Operation::Divide => a / b,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Operation::Add => '+',
+            Operation::Subtract => '-',
+            Operation::Multiply => '*',
+            Operation::Divide => '/',
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Parsed<'input> = HashMap<&'input str, Monkey<'input>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let monkeys: HashMap<_, _> = input.lines().map(parse_monkey).collect::<Result<_, _>>()?;
+
+        let end = &input[input.len()..];
+        for name in ["root", "humn"] {
+            if !monkeys.contains_key(name) {
+                return Err(ParseError::new(end, format!("a monkey named {name}")));
+            }
+        }
+
+        for monkey in monkeys.values() {
+            if let Monkey::Math {
+                dependents: (a, b), ..
+            } = monkey
+            {
+                for name in [a, b] {
+                    if !monkeys.contains_key(name) {
+                        return Err(ParseError::new(name, "a monkey's name"));
+                    }
+                }
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Parsed<'_>) -> Answer {
+        eval_monkey("root", monkeys).into()
+    }
+
+    fn part2(monkeys: &Self::Parsed<'_>) -> Option<Answer> {
+        let Monkey::Math {
+            dependents: (a, b), ..
+        } = monkeys.get("root").unwrap()
+        else {
+            unreachable!()
+        };
+
+        let human = match (generate_term(a, monkeys), generate_term(b, monkeys)) {
+            (Term::Str(_), Term::Num(target)) => solve_for_human(a, target, monkeys),
+            (Term::Num(target), Term::Str(_)) => solve_for_human(b, target, monkeys),
+            _ => unreachable!(),
+        };
+
+        Some(human.into())
+    }
+
+    // A chain of monkeys from the human up to the root, with the root's other
+    // side yelling what the chain comes to for the human's starting number
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let depth = size.ilog2() as usize;
+        let mut troop = Troop {
+            rng,
+            names: HashSet::new(),
+            lines: vec![],
+        };
+
+        let human = troop.rng.range(1..=1_000) as u64;
+        troop.lines.push(format!("humn: {human}"));
+
+        let (mut name, mut value) = ("humn".to_string(), human);
+        for _ in 0..size {
+            let (job, next) = troop.step(&name, value, depth);
+            name = troop.name();
+            troop.lines.push(format!("{name}: {job}"));
+            value = next;
+        }
+
+        let other = troop.number(value, depth);
+        let (left, right) = if troop.rng.chance(0.5) {
+            (name, other)
+        } else {
+            (other, name)
+        };
+        troop.lines.push(format!("root: {left} + {right}"));
+
+        troop.rng.shuffle(&mut troop.lines);
+        troop.lines.join("\n") + "\n"
+    }
+}
+
+fn eval_monkey(monkey: &str, monkeys: &HashMap<&str, Monkey>) -> u64 {
+    match monkeys.get(monkey).unwrap() {
+        Monkey::Number(number) => *number,
+        Monkey::Math {
+            operation,
+            dependents,
+        } => {
+            let (a, b) = dependents;
+            let a = eval_monkey(a, monkeys);
+            let b = eval_monkey(b, monkeys);
+            operation.apply(a, b)
+        }
+        Monkey::Human(number) => *number, // part1
+    }
+}
+
+#[derive(Debug)]
+enum Term {
+    Str(String),
+    Num(u64),
+}
+
+impl std::fmt::Display for Term {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Term::Str(string) => write!(f, "{}", string),
+            Term::Num(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+fn generate_term(monkey: &str, monkeys: &HashMap<&str, Monkey>) -> Term {
+    match monkeys.get(monkey).unwrap() {
+        Monkey::Number(number) => Term::Num(*number),
+        Monkey::Math {
+            operation,
+            dependents,
+        } => {
+            let (a, b) = dependents;
+            let a = generate_term(a, monkeys);
+            let b = generate_term(b, monkeys);
+
+            match (&a, &b) {
+                (Term::Str(_), _) | (_, Term::Str(_)) => {
+                    let operation = operation.to_char();
+                    Term::Str(format!("({a} {operation} {b})"))
+                }
+                (Term::Num(a), Term::Num(b)) => Term::Num(operation.apply(*a, *b)),
+            }
+        }
+        Monkey::Human(_number) => Term::Str("human".into()),
+    }
+}
+
+/// Works backwards from the value `monkey` has to yell, undoing one operation
+/// at a time until reaching the human.
+fn solve_for_human(monkey: &str, target: u64, monkeys: &HashMap<&str, Monkey>) -> u64 {
+    let Monkey::Math {
+        operation,
+        dependents: (a, b),
+    } = monkeys.get(monkey).unwrap()
+    else {
+        return target;
+    };
+
+    match (generate_term(a, monkeys), generate_term(b, monkeys)) {
+        (Term::Str(_), Term::Num(b)) => {
+            let target = match operation {
+                Operation::Add => target - b,
+                Operation::Subtract => target + b,
+                Operation::Multiply => target / b,
+                Operation::Divide => target * b,
+            };
+            solve_for_human(a, target, monkeys)
+        }
+        (Term::Num(a), Term::Str(_)) => {
+            let target = match operation {
+                Operation::Add => target - a,
+                Operation::Subtract => a - target,
+                Operation::Multiply => target / a,
+                Operation::Divide => a / target,
+            };
+            solve_for_human(b, target, monkeys)
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Makes up monkeys whose numbers are all whole: every division is exact and
+// no subtraction goes below zero, with or without the human's number
+struct Troop<'rng> {
+    rng: &'rng mut Rng,
+    names: HashSet<String>,
+    lines: Vec<String>,
+}
+
+impl Troop<'_> {
+    fn name(&mut self) -> String {
+        loop {
+            let name: String = (0..4)
+                .map(|_| (b'a' + self.rng.index(26) as u8) as char)
+                .collect();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    // A monkey, and the ones it waits on, yelling `value`
+    fn number(&mut self, value: u64, depth: usize) -> String {
+        let name = self.name();
+        let job = match self.rng.index(4) {
+            _ if depth == 0 || value < 2 => value.to_string(),
+            0 => {
+                let a = self.rng.range(1..=value as i64 - 1) as u64;
+                let (a, b) = (self.number(a, depth - 1), self.number(value - a, depth - 1));
+                format!("{a} + {b}")
+            }
+            1 => {
+                let b = self.rng.range(1..=20) as u64;
+                let (a, b) = (self.number(value + b, depth - 1), self.number(b, depth - 1));
+                format!("{a} - {b}")
+            }
+            2 => match (2..=5).find(|&factor| value.is_multiple_of(factor)) {
+                Some(factor) => {
+                    let (a, b) = (
+                        self.number(value / factor, depth - 1),
+                        self.number(factor, depth - 1),
+                    );
+                    format!("{a} * {b}")
+                }
+                None => value.to_string(),
+            },
+            _ => {
+                let b = self.rng.range(2..=5) as u64;
+                let (a, b) = (self.number(value * b, depth - 1), self.number(b, depth - 1));
+                format!("{a} / {b}")
+            }
+        };
+
+        self.lines.push(format!("{name}: {job}"));
+        name
+    }
+
+    // The job of a monkey waiting on `name`, which yells `value`, along with
+    // what it yells in turn
+    fn step(&mut self, name: &str, value: u64, depth: usize) -> (String, u64) {
+        loop {
+            match self.rng.index(6) {
+                0 => {
+                    let other = self.rng.range(1..=100) as u64;
+                    let other_name = self.number(other, depth);
+                    let job = if self.rng.chance(0.5) {
+                        format!("{name} + {other_name}")
+                    } else {
+                        format!("{other_name} + {name}")
+                    };
+                    return (job, value + other);
+                }
+                1 if value < 1_000_000_000 => {
+                    let other = self.rng.range(2..=5) as u64;
+                    let other_name = self.number(other, depth);
+                    let job = if self.rng.chance(0.5) {
+                        format!("{name} * {other_name}")
+                    } else {
+                        format!("{other_name} * {name}")
+                    };
+                    return (job, value * other);
+                }
+                2 if value > 1 => {
+                    let other = self.rng.range(1..=(value as i64 - 1).min(100)) as u64;
+                    let other_name = self.number(other, depth);
+                    return (format!("{name} - {other_name}"), value - other);
+                }
+                3 => {
+                    let difference = self.rng.range(1..=100) as u64;
+                    let other_name = self.number(value + difference, depth);
+                    return (format!("{other_name} - {name}"), difference);
+                }
+                4 => {
+                    if let Some(factor) = (2..=5).find(|&factor| value.is_multiple_of(factor)) {
+                        let other_name = self.number(factor, depth);
+                        return (format!("{name} / {other_name}"), value / factor);
+                    }
+                }
+                5 if value < 1_000_000_000 => {
+                    let quotient = self.rng.range(1..=5) as u64;
+                    let other_name = self.number(value * quotient, depth);
+                    return (format!("{other_name} / {name}"), quotient);
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_21::Day21;
+
+// The number `name` yells, or `None` if some division along the way isn't
+// exact
+fn yell(name: &str, jobs: &HashMap<&str, &str>, human: i128) -> Option<i128> {
+    if name == "humn" {
+        return Some(human);
+    }
+
+    let job: Vec<&str> = jobs[name].split(' ').collect();
+    match job[..] {
+        [number] => Some(number.parse().unwrap()),
+        [left, operation, right] => {
+            let (left, right) = (yell(left, jobs, human)?, yell(right, jobs, human)?);
+            match operation {
+                "+" => Some(left + right),
+                "-" => Some(left - right),
+                "*" => Some(left * right),
+                _ => (right != 0 && left % right == 0).then(|| left / right),
+            }
+        }
+        _ => panic!("not a job: {job:?}"),
+    }
+}
+
+// Part 2 tries the human's number counting up from 1, instead of undoing
+// each operation
+fn naive(input: &str) -> Vec<Answer> {
+    let jobs: HashMap<&str, &str> = input
+        .lines()
+        .map(|line| line.split_once(": ").unwrap())
+        .collect();
+    let human: i128 = jobs["humn"].parse().unwrap();
+    let root = yell("root", &jobs, human).unwrap();
+
+    let root_job: Vec<&str> = jobs["root"].split(' ').collect();
+    let matches = |human| {
+        let left = yell(root_job[0], &jobs, human);
+        left.is_some() && left == yell(root_job[2], &jobs, human)
+    };
+    let human = (1..1_000_000).find(|&human| matches(human)).unwrap();
+
+    vec![
+        i64::try_from(root).unwrap().into(),
+        i64::try_from(human).unwrap().into(),
+    ]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day21, _>(1..=12, property::answers::<Day21>, naive);
+}
@@ -0,0 +1,966 @@
+use aoc_common::generate::Rng;
+use aoc_common::geometry::{Direction, Point2};
+use aoc_common::grid::Grid;
+use aoc_common::image::{self, Image, Rgb};
+use aoc_common::parse::{number, split_once, ParseError};
+use aoc_common::solution::{Answer, Implementation, Solution};
+use aoc_common::trace;
+
+use Action::*;
+use Direction::*;
+use Tile::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Open,
+    Wall,
+    Border,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Open),
+            '#' => Ok(Wall),
+            ' ' => Ok(Border),
+            _ => Err(c),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Action {
+    Steps(u8),
+    TurnClockwise,
+    TurnCounterClockwise,
+}
+
+fn turn(facing: Direction, action: &Action) -> Direction {
+    match action {
+        TurnClockwise => facing.turn_right(),
+        TurnCounterClockwise => facing.turn_left(),
+        Steps(_) => unreachable!("{action:?} isn't a turn"),
+    }
+}
+
+fn facing_number(facing: Direction) -> isize {
+    match facing {
+        Right => 0,
+        Down => 1,
+        Left => 2,
+        Up => 3,
+    }
+}
+
+// Steps alternate with turns, starting and ending with steps
+fn parse_actions(mut string: &str) -> Result<Vec<Action>, ParseError> {
+    let mut wow = vec![];
+
+    loop {
+        let digits = string
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(string.len());
+        let (steps, rest) = string.split_at(digits);
+        wow.push(Steps(number(steps)?));
+
+        let turn = match rest.chars().next() {
+            None => return Ok(wow),
+            Some('R') => TurnClockwise,
+            Some('L') => TurnCounterClockwise,
+            Some(_) => return Err(ParseError::new(rest, "a turn (`R` or `L`)")),
+        };
+        wow.push(turn);
+        string = &rest[1..];
+    }
+}
+
+// Which ways a face of the cube runs once folded: along its rows, down its
+// columns, and out of the cube
+type Orientation = [[i8; 3]; 3];
+
+fn negate(vector: [i8; 3]) -> [i8; 3] {
+    vector.map(|c| -c)
+}
+
+/// Each face's top left tile and which way it faces once the map is folded
+/// into a cube, as `[across, down, out]` from the first face's point of view.
+///
+/// The map has to fold up into a cube: six square faces lined up on a grid of
+/// their own size, joined edge to edge so that rolling the cube from one to
+/// the next puts each on a different side.
+#[derive(Debug)]
+pub struct Cube {
+    size: usize,
+    faces: Vec<(Point, Orientation)>,
+}
+
+impl Cube {
+    // Puts each tile in 3D, `size` out from the middle of the cube along its
+    // face's `out` and two units from its neighbors, so that stepping over an
+    // edge is one unit the way it's facing and one back in from the face it's
+    // leaving
+    fn wrap(&self, point: Point, facing: Direction) -> (Point, Direction) {
+        let size = self.size as isize;
+        let dot = |a: [isize; 3], b: [i8; 3]| (0..3).map(|i| a[i] * b[i] as isize).sum::<isize>();
+        let heading = |[across, down, _]: Orientation, facing| match facing {
+            Right => across,
+            Down => down,
+            Left => negate(across),
+            Up => negate(down),
+        };
+
+        let &(corner, orientation @ [across, down, out]) = self
+            .faces
+            .iter()
+            .find(|(corner, _)| {
+                (0..size).contains(&(point.y - corner.y))
+                    && (0..size).contains(&(point.x - corner.x))
+            })
+            .unwrap();
+        let (x, y) = (point.x - corner.x, point.y - corner.y);
+        let towards = heading(orientation, facing);
+        let position: [isize; 3] = std::array::from_fn(|i| {
+            out[i] as isize * size
+                + across[i] as isize * (2 * x - (size - 1))
+                + down[i] as isize * (2 * y - (size - 1))
+                + towards[i] as isize
+                - out[i] as isize
+        });
+
+        let &(corner, orientation) = self
+            .faces
+            .iter()
+            .find(|(_, [_, _, other])| *other == towards)
+            .unwrap();
+        let facing = [Right, Down, Left, Up]
+            .into_iter()
+            .find(|&facing| heading(orientation, facing) == negate(out))
+            .unwrap();
+        let [across, down, _] = orientation;
+        let point = Point {
+            y: corner.y + (dot(position, down) + size - 1) / 2,
+            x: corner.x + (dot(position, across) + size - 1) / 2,
+        };
+
+        (point, facing)
+    }
+}
+
+fn fold_cube<'a>(grid: &Grid<Tile>, map: &'a str) -> Result<Cube, ParseError> {
+    let at = |(y, x): (usize, usize)| -> &'a str {
+        let line = map.lines().nth(y).unwrap_or(&map[map.len()..]);
+        &line[x.min(line.len())..]
+    };
+
+    let tiles = grid.iter().filter(|(_, tile)| **tile != Border).count();
+    let size = (1..).find(|size| 6 * size * size >= tiles).unwrap();
+    if 6 * size * size != tiles {
+        let expected = format!("six square faces, not {tiles} tiles");
+        return Err(ParseError::new(&map[map.len()..], expected));
+    }
+
+    let mut faces = vec![];
+    for face_y in 0..grid.rows().div_ceil(size) {
+        for face_x in 0..grid.columns().div_ceil(size) {
+            let corner = (face_y * size, face_x * size);
+            let on_map =
+                |&position: &(usize, usize)| grid.get(position).unwrap_or(&Border) != &Border;
+            let mut tiles = (0..size * size).map(|i| (corner.0 + i / size, corner.1 + i % size));
+
+            if tiles.clone().all(|position| on_map(&position)) {
+                faces.push((face_y, face_x));
+            } else if tiles.clone().any(|position| on_map(&position)) {
+                let gap = tiles.find(|position| !on_map(position)).unwrap();
+                let expected = format!("a tile, to finish a face {size} wide");
+                return Err(ParseError::new(at(gap), expected));
+            }
+        }
+    }
+
+    let mut orientations: Vec<Option<Orientation>> = vec![None; faces.len()];
+    orientations[0] = Some([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+    let mut stack = vec![0];
+    while let Some(i) = stack.pop() {
+        let (y, x) = faces[i];
+        let [across, down, out] = orientations[i].unwrap();
+        let neighbors = [
+            ((y, x + 1), [negate(out), down, across]),
+            ((y + 1, x), [across, negate(out), down]),
+            ((y, x.wrapping_sub(1)), [out, down, negate(across)]),
+            ((y.wrapping_sub(1), x), [across, out, negate(down)]),
+        ];
+
+        for (face, orientation) in neighbors {
+            if let Some(j) = faces.iter().position(|&other| other == face) {
+                if orientations[j].is_none() {
+                    orientations[j] = Some(orientation);
+                    stack.push(j);
+                }
+            }
+        }
+    }
+
+    for (i, &(y, x)) in faces.iter().enumerate() {
+        let side = orientations[i].map(|[_, _, out]| out);
+        let expected = match side {
+            None => "a face joined to the rest",
+            Some(out)
+                if orientations[..i]
+                    .iter()
+                    .any(|o| o.map(|o| o[2]) == Some(out)) =>
+            {
+                "a face that folds onto a side of its own"
+            }
+            Some(_) => continue,
+        };
+        return Err(ParseError::new(at((y * size, x * size)), expected));
+    }
+
+    let faces = faces
+        .into_iter()
+        .zip(orientations)
+        .map(|((y, x), orientation)| {
+            let corner = Point {
+                y: (y * size) as isize,
+                x: (x * size) as isize,
+            };
+            (corner, orientation.unwrap())
+        })
+        .collect();
+    Ok(Cube { size, faces })
+}
+
+impl Tile {
+    fn as_char(&self) -> char {
+        match self {
+            Open => '.',
+            Wall => '#',
+            Border => ' ',
+        }
+    }
+}
+
+pub type Point = Point2<isize>;
+
+// Every point a walk stepped onto, and which way it was facing
+type Trail = Vec<(Point, Direction)>;
+
+fn move_part1(
+    point: &mut Point,
+    mut steps: u8,
+    facing: Direction,
+    grid: &Grid<Tile>,
+    trail: &mut Trail,
+) {
+    while steps > 0 {
+        let next_point = next_point(*point, facing, grid);
+        match lookup_unchecked(next_point, grid) {
+            Open => {
+                *point = next_point;
+                trail.push((next_point, facing));
+            }
+            Wall => break,
+            Border => unreachable!(),
+        }
+
+        steps -= 1;
+    }
+}
+
+fn move_part2(
+    mut point: Point,
+    mut steps: u8,
+    mut facing: Direction,
+    grid: &Grid<Tile>,
+    wrap: &impl Fn(Point, Direction) -> (Point, Direction),
+    trail: &mut Trail,
+) -> (Point, Direction) {
+    while steps > 0 {
+        let (new_location, new_facing) = next_state(point, facing, grid, wrap);
+        match lookup_unchecked(new_location, grid) {
+            Open => {
+                point = new_location;
+                facing = new_facing;
+                trail.push((point, facing));
+            }
+            Wall => break,
+            Border => unreachable!(),
+        }
+
+        steps -= 1;
+    }
+
+    (point, facing)
+}
+
+fn next_point(point: Point, facing: Direction, grid: &Grid<Tile>) -> Point {
+    let next_point = point.step(facing);
+
+    match lookup(next_point, grid) {
+        Some(Open) | Some(Wall) => next_point,
+        Some(Border) | None => wrap(point, facing, grid),
+    }
+}
+
+fn wrap2(point: Point, facing: Direction, grid: &Grid<Tile>) -> (Point, Direction) {
+    // let face_size = (grid.len() / 3) as isize;
+    let face_size = (grid.columns() / 3) as isize;
+
+    match facing {
+        Right => {
+            if point.y < face_size {
+                // on 6, go to 5, facing left
+                let x = face_size * 2 - 1;
+                let y = face_size - point.y - 1; // mirror
+                let y = y + face_size * 2;
+                (Point { y, x }, Left)
+            } else if point.y < face_size * 2 {
+                // on 4, go to 6, facing up
+                let y = face_size - 1;
+                let x = point.y - face_size;
+                let x = x + face_size * 2;
+                (Point { y, x }, Up)
+            } else if point.y < face_size * 3 {
+                // on 5, go to 6, facing left
+                let x = face_size * 3 - 1;
+                let y = point.y - face_size * 2;
+                let y = face_size - y - 1; // mirror
+                (Point { y, x }, Left)
+            } else {
+                // on 2, go to 5, facing up
+                let y = face_size * 3 - 1;
+                let x = point.y - face_size * 3;
+                let x = x + face_size;
+                (Point { y, x }, Up)
+            }
+        }
+        Left => {
+            if point.y < face_size {
+                // on 1, go to 3, facing right
+                let x = 0;
+                let y = face_size - point.y - 1; // mirror
+                let y = y + face_size * 2;
+                (Point { y, x }, Right)
+            } else if point.y < face_size * 2 {
+                // on 4, go to 3, facing down
+                let y = face_size * 2;
+                let x = point.y - face_size;
+                (Point { y, x }, Down)
+            } else if point.y < face_size * 3 {
+                // on 3, go to 1, facing right
+                let x = face_size;
+                let y = point.y - face_size * 2;
+                let y = face_size - y - 1; // mirror
+                (Point { y, x }, Right)
+            } else {
+                // on 2, go to 1, facing down
+                let y = 0;
+                let x = point.y - face_size * 3;
+                let x = x + face_size;
+                (Point { y, x }, Down)
+            }
+        }
+        Up => {
+            if point.x < face_size {
+                // on 3, go to 4, facing right
+                let x = face_size;
+                let y = point.x + face_size;
+                (Point { y, x }, Right)
+            } else if point.x < face_size * 2 {
+                // on 1, go to 2, facing right
+                let x = 0;
+                let y = point.x - face_size;
+                let y = y + face_size * 3;
+                (Point { y, x }, Right)
+            } else {
+                // on 6, go to 2, facing up
+                let y = face_size * 4 - 1;
+                let x = point.x - face_size * 2;
+                (Point { y, x }, Up)
+            }
+        }
+        Down => {
+            if point.x < face_size {
+                // on 2, go to 6, facing down
+                let y = 0;
+                let x = point.x + face_size * 2;
+                (Point { y, x }, Down)
+            } else if point.x < face_size * 2 {
+                // on 5, go to 2, facing left
+                let x = face_size - 1;
+                let y = point.x - face_size;
+                let y = y + face_size * 3;
+                (Point { y, x }, Left)
+            } else {
+                // on 6, go to 4, facing left
+                let x = face_size * 2 - 1;
+                let y = point.x - face_size * 2;
+                let y = y + face_size;
+                (Point { y, x }, Left)
+            }
+        }
+    }
+}
+
+fn wrap(point: Point, facing: Direction, grid: &Grid<Tile>) -> Point {
+    match facing {
+        Right => {
+            let x = grid
+                .row(point.y as usize)
+                .iter()
+                .position(|tile| *tile != Border)
+                .unwrap() as isize;
+            Point { x, ..point }
+        }
+        Left => {
+            let x = grid
+                .row(point.y as usize)
+                .iter()
+                .rposition(|tile| *tile != Border)
+                .unwrap() as isize;
+            Point { x, ..point }
+        }
+        Up => {
+            let y = grid
+                .column(point.x as usize)
+                .rposition(|tile| *tile != Border)
+                .unwrap() as isize;
+
+            Point { y, ..point }
+        }
+        Down => {
+            let y = grid
+                .column(point.x as usize)
+                .position(|tile| *tile != Border)
+                .unwrap() as isize;
+
+            Point { y, ..point }
+        }
+    }
+}
+
+fn next_state(
+    point: Point,
+    facing: Direction,
+    grid: &Grid<Tile>,
+    wrap: &impl Fn(Point, Direction) -> (Point, Direction),
+) -> (Point, Direction) {
+    let next_point = point.step(facing);
+
+    match lookup(next_point, grid) {
+        Some(Open) | Some(Wall) => (next_point, facing),
+        Some(Border) | None => wrap(point, facing),
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Parsed<'input> = (Grid<Tile>, Vec<Action>, Point, Cube);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (grid, actions) = split_once(input, "\n\n")?;
+        let map = grid;
+        let grid = Grid::parse(map, "a tile (`.`, `#` or ` `)", |c| Tile::try_from(c).ok())?;
+        let cube = fold_cube(&grid, map)?;
+        let actions = parse_actions(actions.trim_end())?;
+
+        let x = grid
+            .row(0)
+            .iter()
+            .position(|tile| *tile == Open)
+            .ok_or_else(|| ParseError::new(input, "an open tile in the first row"))?;
+        let location = Point {
+            y: 0,
+            x: x as isize,
+        };
+
+        Ok((grid, actions, location, cube))
+    }
+
+    fn part1((grid, actions, location, _): &Self::Parsed<'_>) -> Answer {
+        let (location, facing) = walk_part1(*location, Direction::Right, actions, grid);
+        answer(location, facing).into()
+    }
+
+    fn part2((grid, actions, location, cube): &Self::Parsed<'_>) -> Option<Answer> {
+        let wrap = |point, facing| cube.wrap(point, facing);
+        let (location, facing) = walk_cube(*location, Direction::Right, actions, grid, &wrap);
+        Some(answer(location, facing).into())
+    }
+
+    // The six faces laid out the way the real puzzle input has them, which is
+    // the only net `Day22Layout` knows how to fold
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let face = size.max(2);
+        let faces = [(0, 1), (0, 2), (1, 1), (2, 0), (2, 1), (3, 0)];
+        let grid = Grid::from_fn(face * 4, face * 3, |(y, x)| {
+            if !faces.contains(&(y / face, x / face)) {
+                ' '
+            } else if (y, x) != (0, face) && rng.chance(0.1) {
+                '#'
+            } else {
+                '.'
+            }
+        });
+        let map: String = grid
+            .to_string()
+            .lines()
+            .map(|row| row.trim_end().to_string() + "\n")
+            .collect();
+
+        let mut path = rng.range(1..=face as i64 * 2).to_string();
+        for _ in 0..size {
+            let turn = rng.pick(&['L', 'R']);
+            path += &format!("{turn}{}", rng.range(1..=face as i64 * 2));
+        }
+
+        format!("{map}\n{path}\n")
+    }
+}
+
+/// Part 2 wrapping around the real input's net by a table of its edges, where
+/// [`Day22`] folds up whatever net the map has.
+pub struct Day22Layout;
+
+impl Solution for Day22Layout {
+    const DAY: u8 = 22;
+
+    type Parsed<'input> = <Day22 as Solution>::Parsed<'input>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Day22::parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Day22::part1(parsed)
+    }
+
+    fn part2((grid, actions, location, _): &Self::Parsed<'_>) -> Option<Answer> {
+        let (location, facing) = walk_part2(*location, Direction::Right, actions, grid);
+        Some(answer(location, facing).into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        Day22::generate(rng, size)
+    }
+}
+
+pub const ALTERNATIVES: [Implementation; 1] = [Implementation::new::<Day22Layout>(
+    "layout",
+    "wraps part 2 by the real input's net alone, edge by edge",
+)];
+
+fn walk_part1(
+    mut location: Point,
+    mut facing: Direction,
+    actions: &[Action],
+    grid: &Grid<Tile>,
+) -> (Point, Direction) {
+    let mut trail = vec![(location, facing)];
+
+    for action in actions {
+        match action {
+            Steps(steps) => move_part1(&mut location, *steps, facing, grid, &mut trail),
+            TurnClockwise | TurnCounterClockwise => facing = turn(facing, action),
+        }
+        trace!(
+            Round,
+            "action",
+            "{action:?}, now at {location} facing {facing:?}"
+        );
+    }
+
+    trace!(Part, "board", "\n{}", draw_board(grid, location, facing));
+    image::export(Day22::DAY, "path-part1", || paint_path(grid, &trail));
+    (location, facing)
+}
+
+fn walk_part2(
+    location: Point,
+    facing: Direction,
+    actions: &[Action],
+    grid: &Grid<Tile>,
+) -> (Point, Direction) {
+    walk_cube(location, facing, actions, grid, &|point, facing| {
+        wrap2(point, facing, grid)
+    })
+}
+
+// Part 2's walk, going over the cube's edges with `wrap`
+fn walk_cube(
+    mut location: Point,
+    mut facing: Direction,
+    actions: &[Action],
+    grid: &Grid<Tile>,
+    wrap: &impl Fn(Point, Direction) -> (Point, Direction),
+) -> (Point, Direction) {
+    let mut trail = vec![(location, facing)];
+
+    for action in actions {
+        match action {
+            Steps(steps) => {
+                let (new_location, new_facing) =
+                    move_part2(location, *steps, facing, grid, wrap, &mut trail);
+                location = new_location;
+                facing = new_facing;
+            }
+            TurnClockwise | TurnCounterClockwise => facing = turn(facing, action),
+        }
+        trace!(
+            Round,
+            "action",
+            "{action:?}, now at {location} facing {facing:?}"
+        );
+    }
+
+    trace!(Part, "board", "\n{}", draw_board(grid, location, facing));
+    image::export(Day22::DAY, "path-part2", || paint_path(grid, &trail));
+    (location, facing)
+}
+
+// The board with where the walk ended drawn on it
+fn draw_board(grid: &Grid<Tile>, location: Point, facing: Direction) -> String {
+    let mut board = grid.map(Tile::as_char);
+    board[(location.y as usize, location.x as usize)] = facing.arrow();
+    board.to_string()
+}
+
+// The board with every step of the walk colored by the way it faced
+fn paint_path(grid: &Grid<Tile>, trail: &[(Point, Direction)]) -> Image {
+    let mut colors = grid.map(|tile| match tile {
+        Open => Rgb(225, 220, 205),
+        Wall => Rgb(70, 70, 80),
+        Border => Rgb::BLACK,
+    });
+
+    for &(point, facing) in trail {
+        colors[(point.y as usize, point.x as usize)] = match facing {
+            Up => Rgb(40, 120, 220),
+            Down => Rgb(220, 60, 60),
+            Left => Rgb(230, 160, 30),
+            Right => Rgb(40, 170, 80),
+        };
+    }
+
+    Image::from_grid(&colors, |&color| color)
+}
+
+fn answer(point: Point, facing: Direction) -> isize {
+    let row = point.y + 1;
+    let column = point.x + 1;
+    let facing = facing_number(facing);
+
+    1000 * row + 4 * column + facing
+}
+
+fn lookup(point: Point, grid: &Grid<Tile>) -> Option<Tile> {
+    grid.get_signed((point.y, point.x)).copied()
+}
+
+fn lookup_unchecked(point: Point, grid: &Grid<Tile>) -> Tile {
+    grid[(point.y as usize, point.x as usize)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init() -> Grid<Tile> {
+        let input = include_str!("../crafted2.txt");
+
+        let (grid, _actions) = input.split_once("\n\n").unwrap();
+        Grid::parse(grid, "a tile", |c| Tile::try_from(c).ok()).unwrap()
+    }
+
+    #[test]
+    fn fold_wraps_like_the_real_layout() {
+        let (grid, _, _, cube) = Day22::parse(include_str!("../crafted2.txt")).unwrap();
+
+        for ((y, x), _) in grid.iter().filter(|(_, tile)| **tile != Border) {
+            let point = Point {
+                y: y as isize,
+                x: x as isize,
+            };
+            for facing in [Right, Down, Left, Up] {
+                if matches!(lookup(point.step(facing), &grid), Some(Border) | None) {
+                    assert_eq!(
+                        cube.wrap(point, facing),
+                        wrap2(point, facing, &grid),
+                        "stepping {facing:?} from {point}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn up_from_3() {
+        let grid = init();
+
+        let location = Point { y: 8, x: 0 };
+        let facing = Up;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 4, x: 4 });
+        assert_eq!(facing, Right);
+
+        let location = Point { y: 8, x: 3 };
+        let facing = Up;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 7, x: 5 });
+        assert_eq!(facing, Right);
+    }
+
+    #[test]
+    fn up_from_1() {
+        let grid = init();
+
+        let location = Point { y: 0, x: 4 };
+        let facing = Up;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 12, x: 0 });
+        assert_eq!(facing, Right);
+
+        let location = Point { y: 0, x: 7 };
+        let facing = Up;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 15, x: 1 });
+        assert_eq!(facing, Right);
+    }
+
+    #[test]
+    fn up_from_6() {
+        let grid = init();
+
+        let location = Point { y: 0, x: 8 };
+        let facing = Up;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 15, x: 0 });
+        assert_eq!(facing, Up);
+
+        let location = Point { y: 0, x: 11 };
+        let facing = Up;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 14, x: 3 });
+        assert_eq!(facing, Up);
+    }
+
+    #[test]
+    fn down_from_2() {
+        let grid = init();
+
+        let location = Point { y: 15, x: 0 };
+        let facing = Down;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 0, x: 8 });
+        assert_eq!(facing, Down);
+
+        let location = Point { y: 15, x: 3 };
+        let facing = Down;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 1, x: 11 });
+        assert_eq!(facing, Down);
+    }
+
+    #[test]
+    fn down_from_5() {
+        let grid = init();
+
+        let location = Point { y: 11, x: 4 };
+        let facing = Down;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 12, x: 3 });
+        assert_eq!(facing, Left);
+
+        let location = Point { y: 11, x: 7 };
+        let facing = Down;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 15, x: 2 });
+        assert_eq!(facing, Left);
+    }
+
+    #[test]
+    fn down_from_6() {
+        let grid = init();
+
+        let location = Point { y: 3, x: 8 };
+        let facing = Down;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 4, x: 7 });
+        assert_eq!(facing, Left);
+
+        let location = Point { y: 3, x: 11 };
+        let facing = Down;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 7, x: 6 });
+        assert_eq!(facing, Left);
+    }
+
+    #[test]
+    fn left_from_1() {
+        let grid = init();
+
+        let location = Point { y: 0, x: 4 };
+        let facing = Left;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 11, x: 0 });
+        assert_eq!(facing, Right);
+
+        let location = Point { y: 3, x: 4 };
+        let facing = Left;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 8, x: 1 });
+        assert_eq!(facing, Right);
+    }
+
+    #[test]
+    fn left_from_4() {
+        let grid = init();
+
+        let location = Point { y: 4, x: 4 };
+        let facing = Left;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 8, x: 0 });
+        assert_eq!(facing, Down);
+
+        let location = Point { y: 7, x: 4 };
+        let facing = Left;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 9, x: 3 });
+        assert_eq!(facing, Down);
+    }
+
+    #[test]
+    fn left_from_2() {
+        let grid = init();
+
+        let location = Point { y: 12, x: 0 };
+        let facing = Left;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 0, x: 4 });
+        assert_eq!(facing, Down);
+
+        let location = Point { y: 15, x: 0 };
+        let facing = Left;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 1, x: 7 });
+        assert_eq!(facing, Down);
+    }
+
+    #[test]
+    fn left_from_3() {
+        let grid = init();
+
+        let location = Point { y: 8, x: 0 };
+        let facing = Left;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 3, x: 4 });
+        assert_eq!(facing, Right);
+
+        let location = Point { y: 11, x: 0 };
+        let facing = Left;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 0, x: 5 });
+        assert_eq!(facing, Right);
+    }
+
+    #[test]
+    fn right_from_6() {
+        let grid = init();
+
+        let location = Point { y: 0, x: 11 };
+        let facing = Right;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 11, x: 7 });
+        assert_eq!(facing, Left);
+
+        let location = Point { y: 3, x: 11 };
+        let facing = Right;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 8, x: 6 });
+        assert_eq!(facing, Left);
+    }
+
+    #[test]
+    fn right_from_4() {
+        let grid = init();
+
+        let location = Point { y: 4, x: 7 };
+        let facing = Right;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 3, x: 8 });
+        assert_eq!(facing, Up);
+
+        let location = Point { y: 7, x: 7 };
+        let facing = Right;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 2, x: 11 });
+        assert_eq!(facing, Up);
+    }
+
+    #[test]
+    fn right_from_5() {
+        let grid = init();
+
+        let location = Point { y: 8, x: 7 };
+        let facing = Right;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 3, x: 11 });
+        assert_eq!(facing, Left);
+
+        let location = Point { y: 11, x: 7 };
+        let facing = Right;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 0, x: 10 });
+        assert_eq!(facing, Left);
+    }
+
+    #[test]
+    fn right_from_2() {
+        let grid = init();
+
+        let location = Point { y: 12, x: 3 };
+        let facing = Right;
+        let actions = vec![Steps(1)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 11, x: 4 });
+        assert_eq!(facing, Up);
+
+        let location = Point { y: 15, x: 4 };
+        let facing = Right;
+        let actions = vec![Steps(2)];
+        let (location, facing) = walk_part2(location, facing, &actions, &grid);
+        assert_eq!(location, Point { y: 10, x: 7 });
+        assert_eq!(facing, Up);
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::solution::{solve, Answer};
+
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_22::Day22>(env!("CARGO_MANIFEST_DIR"));
+}
+
+// Only the real input's net, which is all `Day22Layout` knows how to fold
+#[test]
+fn layout_folds_the_real_input() {
+    let input = include_str!("../input.txt");
+    let answers = solve::<day_22::Day22Layout>(input, Some(2)).unwrap();
+    assert_eq!(answers[0].answer, Answer::Number(78291));
+}
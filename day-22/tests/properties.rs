@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_22::{Day22, Day22Layout};
+
+type Vector = [i64; 3];
+
+fn add(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(a: Vector, k: i64) -> Vector {
+    a.map(|c| c * k)
+}
+
+fn dot(a: Vector, b: Vector) -> i64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+// Where a face of the net ends up on the cube: its top left corner, the
+// directions its columns and rows run in, and the way out of the cube
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    corner: Vector,
+    across: Vector,
+    down: Vector,
+    out: Vector,
+}
+
+struct Map {
+    rows: Vec<Vec<u8>>,
+    size: i64,
+}
+
+impl Map {
+    fn tile(&self, (y, x): (i64, i64)) -> u8 {
+        let row = usize::try_from(y).ok().and_then(|y| self.rows.get(y));
+        let tile = usize::try_from(x).ok().and_then(|x| row?.get(x));
+        tile.copied().unwrap_or(b' ')
+    }
+
+    // Folds the net up by rolling from face to face across the edges they
+    // share, starting with the first face lying flat
+    fn fold(&self) -> HashMap<(i64, i64), Face> {
+        let first = (0..)
+            .find(|&x| self.tile((0, x * self.size)) != b' ')
+            .unwrap();
+        let mut faces = HashMap::from([(
+            (0, first),
+            Face {
+                corner: [0; 3],
+                across: [1, 0, 0],
+                down: [0, 1, 0],
+                out: [0, 0, -1],
+            },
+        )]);
+
+        let mut stack = vec![(0, first)];
+        while let Some((y, x)) = stack.pop() {
+            let Face {
+                corner,
+                across,
+                down,
+                out,
+            } = faces[&(y, x)];
+            let inward = scale(out, -1);
+            let neighbors = [
+                (
+                    (y, x + 1),
+                    add(corner, scale(across, self.size)),
+                    inward,
+                    down,
+                    across,
+                ),
+                (
+                    (y, x - 1),
+                    add(corner, scale(out, -self.size)),
+                    out,
+                    down,
+                    scale(across, -1),
+                ),
+                (
+                    (y + 1, x),
+                    add(corner, scale(down, self.size)),
+                    across,
+                    inward,
+                    down,
+                ),
+                (
+                    (y - 1, x),
+                    add(corner, scale(out, -self.size)),
+                    across,
+                    out,
+                    scale(down, -1),
+                ),
+            ];
+
+            for (net, corner, across, down, out) in neighbors {
+                let on_net = self.tile((net.0 * self.size, net.1 * self.size)) != b' ';
+                if on_net && !faces.contains_key(&net) {
+                    faces.insert(
+                        net,
+                        Face {
+                            corner,
+                            across,
+                            down,
+                            out,
+                        },
+                    );
+                    stack.push(net);
+                }
+            }
+        }
+
+        faces
+    }
+}
+
+// One step from `(y, x)` going `(dy, dx)` on the map, wrapping round the
+// row or column
+fn step_flat(map: &Map, (y, x): (i64, i64), (dy, dx): (i64, i64)) -> ((i64, i64), (i64, i64)) {
+    let mut next = (y + dy, x + dx);
+    if map.tile(next) == b' ' {
+        next = (y, x);
+        while map.tile((next.0 - dy, next.1 - dx)) != b' ' {
+            next = (next.0 - dy, next.1 - dx);
+        }
+    }
+
+    (next, (dy, dx))
+}
+
+// One step on the folded cube, working in doubled coordinates so every
+// tile's center is a whole number
+fn step_cube(
+    map: &Map,
+    faces: &HashMap<(i64, i64), Face>,
+    (y, x): (i64, i64),
+    (dy, dx): (i64, i64),
+) -> ((i64, i64), (i64, i64)) {
+    let next = (y + dy, x + dx);
+    let same_face =
+        (next.0.div_euclid(map.size), next.1.div_euclid(map.size)) == (y / map.size, x / map.size);
+    if same_face {
+        return (next, (dy, dx));
+    }
+
+    let face = faces[&(y / map.size, x / map.size)];
+    let (row, column) = (y % map.size, x % map.size);
+    let center = add(
+        scale(face.corner, 2),
+        add(
+            scale(face.across, 2 * column + 1),
+            scale(face.down, 2 * row + 1),
+        ),
+    );
+    let heading = add(scale(face.across, dx), scale(face.down, dy));
+
+    // Over the edge and along the next face, away from the one we left
+    let center = add(add(center, heading), scale(face.out, -1));
+    let heading = scale(face.out, -1);
+
+    let (&(face_y, face_x), next_face) = faces
+        .iter()
+        .find(|(_, next_face)| next_face.out == add(scale(face.across, dx), scale(face.down, dy)))
+        .unwrap();
+    let offset = add(center, scale(next_face.corner, -2));
+    let column = (dot(offset, next_face.across) - 1) / 2;
+    let row = (dot(offset, next_face.down) - 1) / 2;
+
+    (
+        (face_y * map.size + row, face_x * map.size + column),
+        (dot(heading, next_face.down), dot(heading, next_face.across)),
+    )
+}
+
+fn password(
+    map: &Map,
+    path: &str,
+    mut step: impl FnMut((i64, i64), (i64, i64)) -> ((i64, i64), (i64, i64)),
+) -> i64 {
+    let mut position = (0, (0..).find(|&x| map.tile((0, x)) == b'.').unwrap());
+    let mut facing = (0, 1);
+
+    let mut steps = 0;
+    for c in path.trim_end().chars().chain(['L', 'R']) {
+        if let Some(digit) = c.to_digit(10) {
+            steps = steps * 10 + digit;
+            continue;
+        }
+
+        for _ in 0..steps {
+            let (next, next_facing) = step(position, facing);
+            if map.tile(next) == b'#' {
+                break;
+            }
+            (position, facing) = (next, next_facing);
+        }
+        steps = 0;
+
+        let (dy, dx) = facing;
+        facing = if c == 'L' { (-dx, dy) } else { (dx, -dy) };
+    }
+
+    let facing = match facing {
+        (0, 1) => 0,
+        (1, 0) => 1,
+        (0, -1) => 2,
+        _ => 3,
+    };
+    1000 * (position.0 + 1) + 4 * (position.1 + 1) + facing
+}
+
+// Part 2 folds any net into a cube in 3D instead of knowing how the real
+// input's edges join up
+fn naive(input: &str) -> Vec<Answer> {
+    let (map, path) = input.split_once("\n\n").unwrap();
+    let rows: Vec<Vec<u8>> = map.lines().map(|line| line.as_bytes().to_vec()).collect();
+    let tiles = rows.iter().flatten().filter(|&&tile| tile != b' ').count();
+    let size = (1..).find(|size| 6 * size * size >= tiles).unwrap() as i64;
+    let map = Map { rows, size };
+
+    let faces = map.fold();
+    vec![
+        password(&map, path, |position, facing| {
+            step_flat(&map, position, facing)
+        })
+        .into(),
+        password(&map, path, |position, facing| {
+            step_cube(&map, &faces, position, facing)
+        })
+        .into(),
+    ]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day22, _>(1..=12, property::answers::<Day22>, naive);
+}
+
+#[test]
+fn layout_agrees_with_naive() {
+    property::agree::<Day22Layout, _>(1..=12, property::answers::<Day22Layout>, naive);
+}
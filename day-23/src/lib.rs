@@ -0,0 +1,238 @@
+use fnv::FnvHashMap as HashMap;
+use fnv::FnvHashSet as HashSet;
+
+use aoc_common::animate;
+use aoc_common::bitset::GrowableBitSet;
+use aoc_common::config::{self, Parameter};
+use aoc_common::generate::Rng;
+use aoc_common::geometry::{Direction, Point2};
+use aoc_common::grid::Grid;
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+
+use Direction::*;
+
+pub type Point = Point2<isize>;
+
+// An elf moves in a direction only if the three points that way are empty
+fn looking(elf: Point, direction: Direction) -> [Point; 3] {
+    let ahead = elf.step(direction);
+    [
+        ahead.step(direction.turn_left()),
+        ahead,
+        ahead.step(direction.turn_right()),
+    ]
+}
+
+type DirectionsToConsider = [Direction; 4];
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const PARAMETERS: &'static [Parameter] = &[Parameter::new::<usize>("part1_rounds")];
+
+    type Parsed<'input> = HashSet<Point>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut elves: HashSet<Point> = HashSet::default();
+        for (y, row) in input.lines().enumerate() {
+            for (x, c) in row.char_indices() {
+                match c {
+                    '#' => {
+                        let (y, x) = (y as isize, x as isize);
+                        elves.insert(Point::new(y, x));
+                    }
+                    '.' => {}
+                    _ => return Err(ParseError::new(&row[x..], "an elf (`#`) or `.`")),
+                }
+            }
+        }
+
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Parsed<'_>) -> Answer {
+        let mut directions_to_consider = directions_to_consider();
+        let mut elves = elves.clone();
+
+        let rounds: usize = config::parameter(Self::DAY, "part1_rounds", 10);
+        for i in 1..=rounds {
+            elves = round(&elves, directions_to_consider.next().unwrap());
+            animate!("round {i}\n{}", draw_elves(&elves));
+        }
+
+        let (top_left, bottom_right) = smallest_rectangle(&elves);
+        (area(top_left, bottom_right) - elves.len()).into()
+    }
+
+    fn part2(elves: &Self::Parsed<'_>) -> Option<Answer> {
+        let mut directions_to_consider = directions_to_consider();
+        let mut elves = elves.clone();
+
+        for i in 1.. {
+            let new_elves = round(&elves, directions_to_consider.next().unwrap());
+
+            if elves == new_elves {
+                return Some(i.into());
+            }
+
+            animate!("round {i}\n{}", draw_elves(&new_elves));
+            elves = new_elves;
+        }
+
+        unreachable!()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut grid = Grid::from_fn(size, size, |_| if rng.chance(0.4) { '#' } else { '.' });
+        // Always at least one elf, so there is a rectangle to measure
+        *grid.get_mut((size / 2, size / 2)).unwrap() = '#';
+        grid.to_string()
+    }
+}
+
+fn directions_to_consider() -> impl Iterator<Item = DirectionsToConsider> {
+    [
+        [Up, Down, Left, Right],
+        [Down, Left, Right, Up],
+        [Left, Right, Up, Down],
+        [Right, Up, Down, Left],
+    ]
+    .into_iter()
+    .cycle()
+}
+
+fn round(elves: &HashSet<Point>, directions_to_consider: DirectionsToConsider) -> HashSet<Point> {
+    let (how_many_elves_per_destination, destinations) = propose(elves, directions_to_consider);
+    act(how_many_elves_per_destination, destinations)
+}
+
+fn propose(
+    elves: &HashSet<Point>,
+    directions_to_consider: DirectionsToConsider,
+) -> (HashMap<Point, usize>, HashMap<Point, Point>) {
+    let mut how_many_elves_per_destination: HashMap<Point, usize> = HashMap::default();
+    let mut destinations: HashMap<Point, Point> = HashMap::default();
+    let occupied = Occupied::new(elves);
+
+    'elves: for elf in elves {
+        if stranded(elf, &occupied) {
+            // No neighbors were around, stay still
+            destinations.insert(*elf, *elf);
+            continue;
+        }
+
+        for direction in directions_to_consider {
+            let any_elves_in_direction = looking(*elf, direction)
+                .into_iter()
+                .any(|point| occupied.contains(point));
+
+            if !any_elves_in_direction {
+                let destination = elf.step(direction);
+                how_many_elves_per_destination
+                    .entry(destination)
+                    .and_modify(|count| *count += 1)
+                    .or_insert(1);
+                destinations.insert(*elf, destination);
+                continue 'elves;
+            }
+        }
+
+        // Couldn't move in any direction, stay still
+        destinations.insert(*elf, *elf);
+    }
+
+    (how_many_elves_per_destination, destinations)
+}
+
+fn stranded(elf: &Point, occupied: &Occupied) -> bool {
+    elf.neighbors8()
+        .all(|neighbor| !occupied.contains(neighbor))
+}
+
+// Where the elves are at the start of a round, as a bit per point of their
+// rectangle, which is quicker to look up than hashing every neighbor
+struct Occupied {
+    top_left: Point,
+    bottom_right: Point,
+    bits: GrowableBitSet,
+}
+
+impl Occupied {
+    fn new(elves: &HashSet<Point>) -> Self {
+        let (top_left, bottom_right) = smallest_rectangle(elves);
+        let mut occupied = Self {
+            top_left,
+            bottom_right,
+            bits: GrowableBitSet::with_capacity(area(top_left, bottom_right)),
+        };
+        for &elf in elves {
+            let index = occupied.index(elf).unwrap();
+            occupied.bits.insert(index);
+        }
+
+        occupied
+    }
+
+    // `None` outside the rectangle, where there are no elves
+    fn index(&self, point: Point) -> Option<usize> {
+        let inside = (self.top_left.y..=self.bottom_right.y).contains(&point.y)
+            && (self.top_left.x..=self.bottom_right.x).contains(&point.x);
+        let width = self.bottom_right.x - self.top_left.x + 1;
+
+        inside.then(|| ((point.y - self.top_left.y) * width + point.x - self.top_left.x) as usize)
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        self.index(point)
+            .is_some_and(|index| self.bits.contains(index))
+    }
+}
+
+fn act(
+    how_many_elves_per_destination: HashMap<Point, usize>,
+    destinations: HashMap<Point, Point>,
+) -> HashSet<Point> {
+    destinations
+        .into_iter()
+        .map(|(elf, new_elf)| {
+            if how_many_elves_per_destination.get(&new_elf).unwrap_or(&0) > &1 {
+                elf
+            } else {
+                new_elf
+            }
+        })
+        .collect()
+}
+
+fn draw_elves(elves: &HashSet<Point>) -> Grid<char> {
+    Grid::from_points(elves.iter().map(|&elf| (elf, '#')), '.')
+}
+
+fn smallest_rectangle(elves: &HashSet<Point>) -> (Point, Point) {
+    let mut max_x = isize::MIN;
+    let mut max_y = isize::MIN;
+    let mut min_x = isize::MAX;
+    let mut min_y = isize::MAX;
+
+    for elf in elves {
+        max_x = elf.x.max(max_x);
+        max_y = elf.y.max(max_y);
+        min_x = elf.x.min(min_x);
+        min_y = elf.y.min(min_y);
+    }
+
+    let top_left = Point::new(min_y, min_x);
+    let bottom_right = Point::new(max_y, max_x);
+
+    (top_left, bottom_right)
+}
+
+fn area(top_left: Point, bottom_right: Point) -> usize {
+    let width = bottom_right.x - top_left.x + 1;
+    let height = bottom_right.y - top_left.y + 1;
+
+    (width * height) as usize
+}
@@ -0,0 +1,95 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_23::Day23;
+
+const NORTH: [(i64, i64); 3] = [(-1, -1), (-1, 0), (-1, 1)];
+const SOUTH: [(i64, i64); 3] = [(1, -1), (1, 0), (1, 1)];
+const WEST: [(i64, i64); 3] = [(-1, -1), (0, -1), (1, -1)];
+const EAST: [(i64, i64); 3] = [(-1, 1), (0, 1), (1, 1)];
+
+// Moves every elf that can for one round, returning whether any did
+fn spread(elves: &mut HashSet<(i64, i64)>, round: usize) -> bool {
+    let free = |(y, x): (i64, i64), around: &[(i64, i64)]| {
+        around
+            .iter()
+            .all(|(dy, dx)| !elves.contains(&(y + dy, x + dx)))
+    };
+    let all_around: Vec<(i64, i64)> = [NORTH, SOUTH, WEST, EAST].concat();
+
+    let mut proposals = HashMap::new();
+    for &elf in elves.iter() {
+        if free(elf, &all_around) {
+            continue;
+        }
+
+        let sides = [NORTH, SOUTH, WEST, EAST];
+        let chosen = (0..4)
+            .map(|i| sides[(round + i) % 4])
+            .find(|side| free(elf, side));
+        if let Some(side) = chosen {
+            let (dy, dx) = side[1];
+            proposals.insert(elf, (elf.0 + dy, elf.1 + dx));
+        }
+    }
+
+    let mut wanted: HashMap<(i64, i64), usize> = HashMap::new();
+    for destination in proposals.values() {
+        *wanted.entry(*destination).or_default() += 1;
+    }
+
+    let mut moved = false;
+    *elves = elves
+        .iter()
+        .map(|elf| match proposals.get(elf) {
+            Some(destination) if wanted[destination] == 1 => {
+                moved = true;
+                *destination
+            }
+            _ => *elf,
+        })
+        .collect();
+    moved
+}
+
+// Every elf in a set, looking round itself each time it moves
+fn naive(input: &str) -> Vec<Answer> {
+    let mut elves: HashSet<(i64, i64)> = input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.char_indices()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| (y as i64, x as i64))
+        })
+        .collect();
+
+    let mut part1 = None;
+    let mut still = None;
+    let mut round = 0;
+    while part1.is_none() || still.is_none() {
+        if !spread(&mut elves, round) {
+            still.get_or_insert(round + 1);
+        }
+        round += 1;
+        if round == 10 {
+            part1 = Some(empty_ground(&elves));
+        }
+    }
+
+    vec![part1.unwrap().into(), still.unwrap().into()]
+}
+
+fn empty_ground(elves: &HashSet<(i64, i64)>) -> i64 {
+    let ys = elves.iter().map(|elf| elf.0);
+    let xs = elves.iter().map(|elf| elf.1);
+    let height = ys.clone().max().unwrap() - ys.min().unwrap() + 1;
+    let width = xs.clone().max().unwrap() - xs.min().unwrap() + 1;
+    height * width - elves.len() as i64
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day23, _>(1..=10, property::answers::<Day23>, naive);
+}
@@ -0,0 +1,287 @@
+use aoc_common::animate;
+use aoc_common::generate::Rng;
+use aoc_common::geometry::{Direction, Point2};
+use aoc_common::grid::Grid;
+use aoc_common::numeric::lcm;
+use aoc_common::parse::ParseError;
+use aoc_common::search::bfs;
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::trace;
+
+// Where the blizzards are on each minute, until they start repeating
+type Snapshots = Vec<Snapshot>;
+type Snapshot = Grid<bool>;
+
+// Points are inside the walls, so the top left corner of the valley is (0, 0)
+pub type Point = Point2<usize>;
+
+#[derive(Debug)]
+struct Blizzard {
+    point: Point,
+    direction: Direction,
+}
+
+impl Blizzard {
+    fn advance(&mut self, size: Point) {
+        self.point = self.point.wrapping_step(self.direction, size);
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Parsed<'input> = (Snapshots, Point, Point);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut blizzards = vec![];
+        let number_of_rows = input.lines().count().saturating_sub(2);
+        let number_of_columns = input
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .len()
+            .saturating_sub(2);
+        if number_of_rows == 0 || number_of_columns == 0 {
+            return Err(ParseError::new(input, "a valley surrounded by walls"));
+        }
+        check_walls(input, number_of_columns)?;
+
+        for (y, row) in input.lines().skip(1).take(number_of_rows).enumerate() {
+            for (x, c) in row
+                .char_indices()
+                .skip(1)
+                .take(number_of_columns)
+                .filter(|(_, c)| *c != '.')
+            {
+                let direction = Direction::from_arrow(c)
+                    .ok_or_else(|| ParseError::new(&row[x..], "a blizzard or `.`"))?;
+                let point = Point::new(y, x - 1);
+                let blizzard = Blizzard { direction, point };
+
+                blizzards.push(blizzard);
+            }
+        }
+
+        let size = Point::new(number_of_rows, number_of_columns);
+        let full_grid = populate_grid(&mut blizzards, size);
+        let start = Point::new(0, 0);
+        let end = Point::new(number_of_rows - 1, number_of_columns - 1);
+
+        Ok((full_grid, start, end))
+    }
+
+    fn part1((full_grid, start, end): &Self::Parsed<'_>) -> Answer {
+        search(full_grid, 0, *start, *end).unwrap().into()
+    }
+
+    fn part2((full_grid, start, end): &Self::Parsed<'_>) -> Option<Answer> {
+        let there = search(full_grid, 0, *start, *end).unwrap();
+        let back = search(full_grid, there, *end, *start).unwrap();
+        let there_again = search(full_grid, back, *start, *end).unwrap();
+        Some(there_again.into())
+    }
+
+    // Random valleys can be walled off by blizzards, so ones without a way
+    // through in both directions are thrown away
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        loop {
+            let input = write_valley(rng, size);
+            let (full_grid, start, end) = Self::parse(&input).unwrap();
+            if search(&full_grid, 0, start, end).is_some()
+                && search(&full_grid, 0, end, start).is_some()
+            {
+                return input;
+            }
+        }
+    }
+}
+
+// The valley is walled in all round, with one opening above its top left
+// corner and one below its bottom right. Tiles are counted in characters, so
+// anything that isn't ASCII is reported rather than split in two
+fn check_walls(input: &str, columns: usize) -> Result<(), ParseError> {
+    let last = input.lines().count() - 1;
+
+    for (y, line) in input.lines().enumerate() {
+        let mut tiles = line.char_indices();
+        for x in 0..columns + 2 {
+            let (at, found) = tiles.next().unwrap_or((line.len(), '\n'));
+            let (tile, expected) = match (y, x) {
+                (0, 1) => ('.', "an opening (`.`) above the valley's top left corner"),
+                (y, x) if y == last && x == columns => (
+                    '.',
+                    "an opening (`.`) below the valley's bottom right corner",
+                ),
+                (0, _) | (_, 0) => ('#', "a wall (`#`)"),
+                (y, x) if y == last || x == columns + 1 => ('#', "a wall (`#`)"),
+                _ => continue,
+            };
+
+            if found != tile {
+                return Err(ParseError::new(&line[at..], expected));
+            }
+        }
+
+        if let Some((at, _)) = tiles.next() {
+            return Err(ParseError::new(&line[at..], "end of line"));
+        }
+    }
+
+    Ok(())
+}
+
+// Searches over where the expedition is and the minute within the blizzards'
+// period, starting from the door beside `start_location` on `start_minute` and
+// returning the minute it steps out of the door beside `end_location`. `None`
+// is waiting at the start door, where no blizzard can reach.
+fn search(
+    full_grid: &Snapshots,
+    start_minute: usize,
+    start_location: Point,
+    end_location: Point,
+) -> Option<usize> {
+    let period = full_grid.len();
+    let moves = |&(location, minute): &(Option<Point>, usize)| {
+        let minute = (minute + 1) % period;
+        let grid = &full_grid[minute];
+
+        let moves: Vec<Option<Point>> = match location {
+            None => vec![None, Some(start_location)],
+            Some(location) if location == start_location => location
+                .neighbors4()
+                .chain([location])
+                .map(Some)
+                .chain([None])
+                .collect(),
+            Some(location) => location.neighbors4().chain([location]).map(Some).collect(),
+        };
+
+        moves
+            .into_iter()
+            .filter(|neighbor| match neighbor {
+                None => true,
+                Some(neighbor) => grid.get((*neighbor).into()) == Some(&false),
+            })
+            .map(|neighbor| (neighbor, minute))
+            .collect::<Vec<_>>()
+    };
+
+    let start = (None, start_minute % period);
+    let search = bfs(start, moves, |&(location, _)| {
+        location == Some(end_location)
+    });
+
+    if animate::enabled() {
+        let path = search.goal.and_then(|goal| search.path(&goal));
+        for (i, (location, minute)) in path.unwrap_or_default().into_iter().enumerate() {
+            animate!(
+                "minute {}\n{}",
+                start_minute + i,
+                draw_expedition(&full_grid[minute], location)
+            );
+        }
+    }
+    let arrival = search.goal_cost().map(|minutes| start_minute + minutes + 1);
+    trace!(
+        Round,
+        "trip",
+        "left {start_location} on minute {start_minute}, {}",
+        match arrival {
+            Some(minute) => format!("reached {end_location} on minute {minute}"),
+            None => format!("never reached {end_location}"),
+        }
+    );
+    arrival
+}
+
+// Every blizzard moves in a straight line and wraps around, those going
+// across the valley every `size.x` minutes and those going up or down it
+// every `size.y`, so they're all back where they started after the lcm of the
+// two
+fn populate_grid(blizzards: &mut [Blizzard], size: Point) -> Snapshots {
+    let period = lcm(size.y, size.x);
+    trace!(
+        Part,
+        "period",
+        "the blizzards repeat every {period} minutes"
+    );
+
+    let mut final_grid = vec![];
+    for _ in 0..period {
+        trace!(
+            Step,
+            "blizzards",
+            "minute {}\n{}",
+            final_grid.len(),
+            draw_blizzards(blizzards, size)
+        );
+
+        let mut grid = Grid::new(size.y, size.x, false);
+
+        for blizzard in blizzards.iter_mut() {
+            grid[blizzard.point] = true;
+            blizzard.advance(size);
+        }
+
+        final_grid.push(grid);
+    }
+
+    final_grid
+}
+
+// The valley inside the walls, with the expedition as `E` and blizzards as `#`
+fn draw_expedition(snapshot: &Snapshot, location: Option<Point>) -> Grid<char> {
+    let mut grid = snapshot.map(|&blizzard| if blizzard { '#' } else { '.' });
+    if let Some(location) = location {
+        grid[location] = 'E';
+    }
+    grid
+}
+
+// The valley inside the walls, with the last of the blizzards on each square
+// drawn over the others
+fn draw_blizzards(blizzards: &[Blizzard], size: Point) -> String {
+    let mut grid = Grid::new(size.y, size.x, '.');
+
+    for blizzard in blizzards {
+        grid[blizzard.point] = blizzard.direction.arrow();
+    }
+
+    grid.to_string()
+}
+
+// A valley with the way in above its top left corner and the way out below
+// its bottom right, with a blizzard on about a third of it. None blow along
+// the edges next to the two corners, so those stay clear to wait in
+fn write_valley(rng: &mut Rng, size: usize) -> String {
+    let (rows, columns) = (size.max(2), size.max(2) * 2);
+    let wall = |gap: usize| -> String {
+        (0..columns + 2)
+            .map(|x| if x == gap { '.' } else { '#' })
+            .collect()
+    };
+
+    let mut output = wall(1) + "\n";
+    for y in 0..rows {
+        let valley: String = (0..columns)
+            .map(|x| {
+                let blizzards: &[char] = match (y % (rows - 1) == 0, x % (columns - 1) == 0) {
+                    (true, true) => &[],
+                    (true, false) => &['^', 'v'],
+                    (false, true) => &['<', '>'],
+                    (false, false) => &['^', 'v', '<', '>'],
+                };
+                if !blizzards.is_empty() && rng.chance(0.3) {
+                    *rng.pick(blizzards)
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        output += &format!("#{valley}#\n");
+    }
+
+    output + &wall(columns) + "\n"
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_24::Day24>(env!("CARGO_MANIFEST_DIR"));
+}
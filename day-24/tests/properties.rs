@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_24::Day24;
+
+struct Valley {
+    tiles: Vec<Vec<u8>>,
+    rows: i64,
+    columns: i64,
+}
+
+impl Valley {
+    // Looks back along each blizzard's line for one that would be at
+    // `(y, x)` by `minute`
+    fn clear(&self, (y, x): (i64, i64), minute: i64) -> bool {
+        if (y, x) == (-1, 0) || (y, x) == (self.rows, self.columns - 1) {
+            return true;
+        }
+        if !(0..self.rows).contains(&y) || !(0..self.columns).contains(&x) {
+            return false;
+        }
+
+        let tile = |y: i64, x: i64| {
+            self.tiles[y.rem_euclid(self.rows) as usize][x.rem_euclid(self.columns) as usize]
+        };
+        tile(y, x - minute) != b'>'
+            && tile(y, x + minute) != b'<'
+            && tile(y - minute, x) != b'v'
+            && tile(y + minute, x) != b'^'
+    }
+
+    // Every place the expedition could be each minute, until one of them
+    // is `to`
+    fn cross(&self, from: (i64, i64), to: (i64, i64), mut minute: i64) -> i64 {
+        let mut frontier = HashSet::from([from]);
+        while !frontier.contains(&to) {
+            minute += 1;
+            assert!(minute < 100_000, "no way through");
+            frontier = frontier
+                .iter()
+                .flat_map(|&(y, x)| [(y, x), (y - 1, x), (y + 1, x), (y, x - 1), (y, x + 1)])
+                .filter(|&position| self.clear(position, minute))
+                .collect();
+        }
+
+        minute
+    }
+}
+
+// Spreads out from the entrance a minute at a time instead of searching
+fn naive(input: &str) -> Vec<Answer> {
+    let lines: Vec<&str> = input.lines().collect();
+    let tiles: Vec<Vec<u8>> = lines[1..lines.len() - 1]
+        .iter()
+        .map(|line| line.as_bytes()[1..line.len() - 1].to_vec())
+        .collect();
+    let valley = Valley {
+        rows: tiles.len() as i64,
+        columns: tiles[0].len() as i64,
+        tiles,
+    };
+
+    let (entrance, exit) = ((-1, 0), (valley.rows, valley.columns - 1));
+    let there = valley.cross(entrance, exit, 0);
+    let back = valley.cross(exit, entrance, there);
+    let there_again = valley.cross(entrance, exit, back);
+    vec![there.into(), there_again.into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day24, _>(1..=12, property::answers::<Day24>, naive);
+}
@@ -0,0 +1,286 @@
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+use aoc_common::generate::Rng;
+use aoc_common::numeric;
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Implementation, Solution};
+
+fn snafu_to_decimal(snafu: &Snafu) -> i64 {
+    snafu
+        .digits
+        .iter()
+        .rev()
+        .fold(0, |sum, digit| sum * 5 + digit.value())
+}
+
+fn as_snafu_digit(num: i64) -> char {
+    match num {
+        -2 => '=',
+        -1 => '-',
+        0 => '0',
+        1 => '1',
+        2 => '2',
+        _ => unreachable!(),
+    }
+}
+
+fn wrap_digit(num: i64) -> i64 {
+    match num {
+        0..=2 => num,
+        3 | 4 => num - 5,
+        _ => unreachable!(),
+    }
+}
+
+// Negative numbers too, whose most significant digit is `-` or `=`
+fn as_snafu(mut num: i64) -> String {
+    if num == 0 {
+        return "0".to_string();
+    }
+
+    let mut string = String::new();
+    while num != 0 {
+        let ones = numeric::wrap(num, 5);
+        let adjustment = wrap_digit(ones);
+        let digit = as_snafu_digit(adjustment);
+        string.push(digit);
+        num -= adjustment;
+        num /= 5;
+    }
+
+    string.chars().rev().collect()
+}
+
+use SnafuDigit::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum SnafuDigit {
+    Zero,
+    One,
+    Two,
+    NegOne,
+    NegTwo,
+}
+
+impl Add<SnafuDigit> for SnafuDigit {
+    // Returns the digit and a second digit indicating carry
+    type Output = (SnafuDigit, SnafuDigit);
+
+    fn add(self, rhs: SnafuDigit) -> Self::Output {
+        match (self, rhs) {
+            (Zero, _) => (rhs, Zero),
+            (_, Zero) => (self, Zero),
+            (One, One) => (Two, Zero),
+            (One, Two) => (NegTwo, One),
+            (One, NegOne) => (Zero, Zero),
+            (One, NegTwo) => (NegOne, Zero),
+            (Two, One) => (NegTwo, One),
+            (Two, Two) => (NegOne, One),
+            (Two, NegOne) => (One, Zero),
+            (Two, NegTwo) => (Zero, Zero),
+            (NegOne, One) => (Zero, Zero),
+            (NegOne, Two) => (One, Zero),
+            (NegOne, NegOne) => (NegTwo, Zero),
+            (NegOne, NegTwo) => (Two, NegOne),
+            (NegTwo, One) => (NegOne, Zero),
+            (NegTwo, Two) => (Zero, Zero),
+            (NegTwo, NegOne) => (Two, NegOne),
+            (NegTwo, NegTwo) => (One, NegOne),
+        }
+    }
+}
+
+impl TryFrom<char> for SnafuDigit {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '2' => Ok(Two),
+            '1' => Ok(One),
+            '0' => Ok(Zero),
+            '-' => Ok(NegOne),
+            '=' => Ok(NegTwo),
+            _ => Err(c),
+        }
+    }
+}
+
+impl SnafuDigit {
+    fn value(&self) -> i64 {
+        match *self {
+            NegTwo => -2,
+            NegOne => -1,
+            Zero => 0,
+            One => 1,
+            Two => 2,
+        }
+    }
+
+    fn as_char(&self) -> char {
+        match *self {
+            NegTwo => '=',
+            NegOne => '-',
+            Zero => '0',
+            One => '1',
+            Two => '2',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Snafu {
+    digits: Vec<SnafuDigit>,
+}
+
+impl FromStr for Snafu {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.is_empty() {
+            return Err(ParseError::new(value, "a SNAFU digit"));
+        }
+
+        let digits = value
+            .char_indices()
+            .rev()
+            .map(|(i, c)| {
+                SnafuDigit::try_from(c).map_err(|_| {
+                    ParseError::new(&value[i..], "a SNAFU digit (`2`, `1`, `0`, `-` or `=`)")
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { digits })
+    }
+}
+
+impl std::fmt::Display for Snafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+
+        let s: String = self.digits.iter().rev().map(SnafuDigit::as_char).collect();
+        write!(f, "{s}")
+    }
+}
+
+impl Add<Snafu> for Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: Snafu) -> Self::Output {
+        let mut digits = Vec::with_capacity(self.digits.len().max(rhs.digits.len()));
+        let mut i = 0;
+        let mut previous_carry = Zero;
+
+        loop {
+            let (self_digit, rhs_digit) = match (self.digits.get(i), rhs.digits.get(i)) {
+                (None, None) => break,
+                (None, Some(rhs_digit)) => (Zero, *rhs_digit),
+                (Some(self_digit), None) => (*self_digit, Zero),
+                (Some(self_digit), Some(rhs_digit)) => (*self_digit, *rhs_digit),
+            };
+
+            let (digit, new_carry) = self_digit + rhs_digit;
+            let (digit, carry_carry) = digit + previous_carry;
+            let (next_carry, carry_that_is_always_zero) = new_carry + carry_carry;
+            assert_eq!(Zero, carry_that_is_always_zero);
+
+            digits.push(digit);
+            previous_carry = next_carry;
+
+            i += 1;
+        }
+
+        if previous_carry != Zero {
+            digits.push(previous_carry);
+        }
+        // Digits that cancelled out at the top would be leading zeros
+        while digits.len() > 1 && digits.last() == Some(&Zero) {
+            digits.pop();
+        }
+
+        Snafu { digits }
+    }
+}
+
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |acc, x| acc + x)
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Parsed<'input> = Vec<Snafu>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(numbers: &Self::Parsed<'_>) -> Answer {
+        let sum: Snafu = numbers.iter().cloned().sum();
+        sum.to_string().into()
+    }
+
+    fn part2(_numbers: &Self::Parsed<'_>) -> Option<Answer> {
+        None
+    }
+
+    // Mostly the puzzle's positive numbers, but some negative ones, and now
+    // and then a last one cancelling out the rest, so that sums of zero and
+    // below get solved too
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut numbers: Vec<i64> = (0..size)
+            .map(|_| match rng.chance(0.3) {
+                true => rng.range(-1_000_000_000_000..=-1),
+                false => rng.range(1..=1_000_000_000_000),
+            })
+            .collect();
+        if rng.chance(0.25) {
+            numbers.push(-numbers.iter().sum::<i64>());
+        }
+
+        numbers
+            .iter()
+            .map(|&number| as_snafu(number) + "\n")
+            .collect()
+    }
+}
+
+/// Sums the numbers in decimal and converts back, where [`Day25`] adds them
+/// up digit by digit in SNAFU.
+pub struct Day25Decimal;
+
+impl Solution for Day25Decimal {
+    const DAY: u8 = 25;
+
+    type Parsed<'input> = Vec<Snafu>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Day25::parse(input)
+    }
+
+    fn part1(numbers: &Self::Parsed<'_>) -> Answer {
+        let sum = numbers.iter().map(snafu_to_decimal).sum();
+        as_snafu(sum).into()
+    }
+
+    fn part2(_numbers: &Self::Parsed<'_>) -> Option<Answer> {
+        None
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        Day25::generate(rng, size)
+    }
+}
+
+pub const ALTERNATIVES: [Implementation; 1] = [Implementation::new::<Day25Decimal>(
+    "decimal",
+    "sums in decimal and converts back to SNAFU",
+)];
@@ -0,0 +1,9 @@
+#[test]
+fn answers() {
+    aoc_common::answers::check::<day_25::Day25>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn decimal_answers() {
+    aoc_common::answers::check::<day_25::Day25Decimal>(env!("CARGO_MANIFEST_DIR"));
+}
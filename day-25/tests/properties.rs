@@ -0,0 +1,48 @@
+use aoc_common::property;
+use aoc_common::solution::Answer;
+use day_25::{Day25, Day25Decimal};
+
+// Through ordinary numbers, carrying into the next digit whenever a
+// remainder is too big for a SNAFU digit, with remainders taken the euclidean
+// way so that negative sums carry the same way
+fn naive(input: &str) -> Vec<Answer> {
+    let mut sum: i64 = input
+        .lines()
+        .map(|line| {
+            line.chars().fold(0, |number, c| {
+                let digit = match c {
+                    '=' => -2,
+                    '-' => -1,
+                    _ => c.to_digit(10).unwrap() as i64,
+                };
+                number * 5 + digit
+            })
+        })
+        .sum();
+
+    let mut digits = vec![];
+    while sum != 0 {
+        let (digit, carry) = match sum.rem_euclid(5) {
+            3 => ('=', 1),
+            4 => ('-', 1),
+            remainder => (char::from_digit(remainder as u32, 10).unwrap(), 0),
+        };
+        digits.push(digit);
+        sum = sum.div_euclid(5) + carry;
+    }
+    if digits.is_empty() {
+        digits.push('0');
+    }
+
+    vec![digits.iter().rev().collect::<String>().into()]
+}
+
+#[test]
+fn agrees_with_naive() {
+    property::agree::<Day25, _>(1..=30, property::answers::<Day25>, naive);
+}
+
+#[test]
+fn decimal_agrees_with_naive() {
+    property::agree::<Day25Decimal, _>(1..=30, property::answers::<Day25Decimal>, naive);
+}